@@ -1,5 +1,6 @@
 use criterion::{self, black_box, criterion_group, criterion_main, Criterion};
 use rustic_ml::data_utils::matrix::Matrix;
+use rustic_ml::data_utils::sparse_matrix::{SparseFormat, SparseMatrix};
 
 fn benchmark_matrix_multiplication(c: &mut Criterion) {
     // Define matrix sizes and data
@@ -20,6 +21,63 @@ fn benchmark_matrix_multiplication(c: &mut Criterion) {
     });
 }
 
+fn benchmark_matrix_multiplication_kernels(c: &mut Criterion) {
+    let size = 100;
+    let mat_a = Matrix::with_rand_range(size, size, -1.0..=1.0);
+    let mat_b = Matrix::with_rand_range(size, size, -1.0..=1.0);
+
+    // Compare the naive triple loop with the tiled kernel on a single thread
+    let mut group = c.benchmark_group("matrix_multiplication_kernels_size_100");
+    group.bench_function("naive", |b| {
+        b.iter(|| black_box(mat_a.multiply_naive(black_box(&mat_b)).unwrap()));
+    });
+    group.bench_function("tiled_1_thread", |b| {
+        b.iter(|| black_box(mat_a.multiply_with_threads(black_box(&mat_b), 1).unwrap()));
+    });
+    group.finish();
+}
+
+fn benchmark_matrix_transpose(c: &mut Criterion) {
+    let size = 100;
+    let data: Vec<f32> = (0..size * size).map(|x| x as f32).collect();
+    let mat = Matrix::from_vec(size, data);
+
+    // Benchmark the transposed copy
+    c.bench_function("matrix_get_transposed_size_100", |b| {
+        b.iter(|| {
+            let result: Matrix = black_box(&mat).get_transposed();
+            let _ = black_box(result);
+        });
+    });
+}
+
+fn benchmark_sparse_multiplication(c: &mut Criterion) {
+    // One-hot encoded rows, so 1 of 100 values is stored
+    let size = 100;
+    let data: Vec<f32> = (0..size * size)
+        .map(|x| {
+            if x % size == (x / size * 7) % size {
+                1.0
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let dense = Matrix::from_vec(size, data);
+    let sparse = SparseMatrix::from_dense(&dense, SparseFormat::Csr);
+    let mat_b = Matrix::with_rand_range(size, size, -1.0..=1.0);
+
+    // Compare the dense product with the product that only uses the stored values
+    let mut group = c.benchmark_group("sparse_multiplication_size_100");
+    group.bench_function("dense", |b| {
+        b.iter(|| black_box(dense.multiply(black_box(&mat_b)).unwrap()));
+    });
+    group.bench_function("sparse_csr", |b| {
+        b.iter(|| black_box(sparse.multiply_dense(black_box(&mat_b)).unwrap()));
+    });
+    group.finish();
+}
+
 fn criterion_small_config() -> Criterion {
     Criterion::default()
         .sample_size(100)
@@ -30,7 +88,7 @@ fn criterion_small_config() -> Criterion {
 criterion_group! {
     name = small_benches;
     config = criterion_small_config();
-    targets = benchmark_matrix_multiplication
+    targets = benchmark_matrix_multiplication, benchmark_matrix_multiplication_kernels, benchmark_matrix_transpose, benchmark_sparse_multiplication
 }
 
 // MORE EXTENSIVE BENCHMARK TESTING
@@ -55,6 +113,48 @@ fn benchmark_large_matrix_multiplication(c: &mut Criterion) {
     });
 }
 
+#[cfg(feature = "extensive_benchmark")]
+fn benchmark_large_matrix_multiplication_kernels(c: &mut Criterion) {
+    let size = 1000;
+    let mat_a = Matrix::with_rand_range(size, size, -1.0..=1.0);
+    let mat_b = Matrix::with_rand_range(size, size, -1.0..=1.0);
+    let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+
+    // Compare the naive triple loop with the tiled kernel on one and all cores
+    let mut group = c.benchmark_group("matrix_multiplication_kernels_size_1000");
+    group.bench_function("naive", |b| {
+        b.iter(|| black_box(mat_a.multiply_naive(black_box(&mat_b)).unwrap()));
+    });
+    group.bench_function("tiled_1_thread", |b| {
+        b.iter(|| black_box(mat_a.multiply_with_threads(black_box(&mat_b), 1).unwrap()));
+    });
+    group.bench_function(format!("tiled_{threads}_threads"), |b| {
+        b.iter(|| {
+            black_box(
+                mat_a
+                    .multiply_with_threads(black_box(&mat_b), threads)
+                    .unwrap(),
+            )
+        });
+    });
+    group.finish();
+}
+
+#[cfg(feature = "extensive_benchmark")]
+fn benchmark_large_matrix_transpose(c: &mut Criterion) {
+    let size = 1000;
+    let data: Vec<f32> = (0..size * size).map(|x| x as f32).collect();
+    let mat = Matrix::from_vec(size, data);
+
+    // Benchmark the transposed copy of a large matrix
+    c.bench_function("matrix_get_transposed_size_1000", |b| {
+        b.iter(|| {
+            let result = black_box(&mat).get_transposed();
+            black_box(result);
+        });
+    });
+}
+
 #[cfg(feature = "extensive_benchmark")]
 fn criterion_large_config() -> Criterion {
     Criterion::default()
@@ -67,7 +167,7 @@ fn criterion_large_config() -> Criterion {
 criterion_group! {
     name = large_benches;
     config = criterion_large_config();
-    targets = benchmark_large_matrix_multiplication
+    targets = benchmark_large_matrix_multiplication, benchmark_large_matrix_multiplication_kernels, benchmark_large_matrix_transpose
 }
 
 #[cfg(not(feature = "extensive_benchmark"))]
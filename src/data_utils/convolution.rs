@@ -0,0 +1,214 @@
+use crate::data_utils::float::Float;
+use crate::data_utils::matrix::{Axis, Matrix, MatrixError};
+
+/// Padding of the input of a convolution
+///
+/// Padded values are zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    /// No padding, the kernel is only placed where it fits inside the input
+    Valid,
+
+    /// Pad the input so the output has `ceil(input / stride)` rows and columns, the same shape as the input for stride 1.
+    /// Odd amounts of padding put the extra row and column at the bottom and right, the same as TensorFlow.
+    Same,
+
+    /// Pad the given amount of rows and columns on every side of the input
+    Zeros(usize),
+}
+
+/// Shape of a convolution, and the padding before the first row and column
+struct ConvGeometry {
+    out_rows: usize,
+    out_cols: usize,
+    pad_top: usize,
+    pad_left: usize,
+}
+
+/// Calculate the output shape and padding of placing a `(kernel_rows x kernel_cols)` window on the input
+fn conv_geometry(
+    input: (usize, usize),
+    kernel: (usize, usize),
+    stride: usize,
+    padding: Padding,
+) -> Result<ConvGeometry, MatrixError> {
+    if stride == 0 {
+        return Err(MatrixError::IllegalRange(
+            "The stride of a convolution must be at least 1".to_string(),
+        ));
+    }
+
+    // Padding before and the total padding along one axis
+    let pad = |len: usize, kernel_len: usize| match padding {
+        Padding::Valid => (0, 0),
+        Padding::Same => {
+            let out = len.div_ceil(stride);
+            let total = ((out.max(1) - 1) * stride + kernel_len).saturating_sub(len);
+            (total / 2, total)
+        }
+        Padding::Zeros(amount) => (amount, 2 * amount),
+    };
+    let (pad_top, pad_rows) = pad(input.0, kernel.0);
+    let (pad_left, pad_cols) = pad(input.1, kernel.1);
+
+    let (padded_rows, padded_cols) = (input.0 + pad_rows, input.1 + pad_cols);
+    if kernel.0 == 0 || kernel.1 == 0 || kernel.0 > padded_rows || kernel.1 > padded_cols {
+        return Err(MatrixError::ShapeMismatch {
+            first_matrix_shape: format!("{}x{}", input.0, input.1),
+            second_matrix_shape: format!("{}x{}", kernel.0, kernel.1),
+        });
+    }
+
+    Ok(ConvGeometry {
+        out_rows: (padded_rows - kernel.0) / stride + 1,
+        out_cols: (padded_cols - kernel.1) / stride + 1,
+        pad_top,
+        pad_left,
+    })
+}
+
+// Convolution and pooling of a single channel image stored in a matrix
+impl<T: Float> Matrix<T> {
+    /// Rearrange the windows of the matrix into rows (im2col)
+    ///
+    /// Places a `(kernel_rows x kernel_cols)` window at each output position, moving `stride` values at a time.
+    /// Each row of the result is one window in row-major order, and the rows are the output positions in row-major order.
+    /// A convolution is then a matrix product with the flattened kernel, see `conv2d`.
+    /// Returns `MatrixError::IllegalRange` if the stride is 0,
+    /// and `MatrixError::ShapeMismatch` if the window is empty or larger than the padded matrix.
+    pub fn im2col(
+        &self,
+        kernel_rows: usize,
+        kernel_cols: usize,
+        stride: usize,
+        padding: Padding,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let geometry = conv_geometry(
+            (self.rows, self.cols),
+            (kernel_rows, kernel_cols),
+            stride,
+            padding,
+        )?;
+
+        let window = kernel_rows * kernel_cols;
+        let mut data: Vec<T> = Vec::with_capacity(geometry.out_rows * geometry.out_cols * window);
+        for out_row in 0..geometry.out_rows {
+            for out_col in 0..geometry.out_cols {
+                for kernel_row in 0..kernel_rows {
+                    // Position in the matrix, where values in the padding are outside the range
+                    let row = (out_row * stride + kernel_row).checked_sub(geometry.pad_top);
+                    for kernel_col in 0..kernel_cols {
+                        let col = (out_col * stride + kernel_col).checked_sub(geometry.pad_left);
+                        let value = match (row, col) {
+                            (Some(row), Some(col)) if row < self.rows && col < self.cols => {
+                                self.data[row * self.cols + col]
+                            }
+                            _ => T::ZERO,
+                        };
+                        data.push(value);
+                    }
+                }
+            }
+        }
+
+        Ok(Matrix {
+            data,
+            rows: geometry.out_rows * geometry.out_cols,
+            cols: window,
+        })
+    }
+
+    /// 2D convolution of the matrix with the kernel
+    ///
+    /// Slides the kernel over the matrix, moving `stride` values at a time, and sums the products of the kernel and the values below it.
+    /// The kernel is not flipped, which is cross-correlation, the same as convolution layers in deep learning libraries.
+    /// Uses `im2col` and `Matrix::multiply`, so large inputs use the tiled and multithreaded multiplication.
+    /// Read more: <https://en.wikipedia.org/wiki/Kernel_(image_processing)>
+    ///
+    /// Returns `MatrixError::IllegalRange` if the stride is 0,
+    /// and `MatrixError::ShapeMismatch` if the kernel is empty or larger than the padded matrix.
+    pub fn conv2d(
+        &self,
+        kernel: &Matrix<T>,
+        stride: usize,
+        padding: Padding,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let geometry = conv_geometry(
+            (self.rows, self.cols),
+            (kernel.rows, kernel.cols),
+            stride,
+            padding,
+        )?;
+
+        // The kernel as a column vector, so each window row is multiplied with it
+        let kernel_column = Matrix {
+            data: kernel.data.clone(),
+            rows: kernel.data.len(),
+            cols: 1,
+        };
+        let patches = self.im2col(kernel.rows, kernel.cols, stride, padding)?;
+        let mut result = patches.multiply(&kernel_column)?;
+
+        result.reshape(geometry.out_rows, geometry.out_cols);
+        Ok(result)
+    }
+
+    /// 2D max pooling of the matrix
+    ///
+    /// Takes the largest value in each `(pool_rows x pool_cols)` window, moving `stride` values at a time.
+    /// Windows that do not fit inside the matrix are left out.
+    /// Returns `MatrixError::IllegalRange` if the stride is 0,
+    /// and `MatrixError::ShapeMismatch` if the window is empty or larger than the matrix.
+    pub fn max_pool2d(
+        &self,
+        pool_rows: usize,
+        pool_cols: usize,
+        stride: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        self.pool2d(pool_rows, pool_cols, stride, |windows| {
+            windows.max_axis(Axis::Cols)
+        })
+    }
+
+    /// 2D average pooling of the matrix
+    ///
+    /// Takes the mean of each `(pool_rows x pool_cols)` window, moving `stride` values at a time.
+    /// Windows that do not fit inside the matrix are left out.
+    /// Returns `MatrixError::IllegalRange` if the stride is 0,
+    /// and `MatrixError::ShapeMismatch` if the window is empty or larger than the matrix.
+    pub fn avg_pool2d(
+        &self,
+        pool_rows: usize,
+        pool_cols: usize,
+        stride: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        self.pool2d(pool_rows, pool_cols, stride, |windows| {
+            windows.mean_axis(Axis::Cols)
+        })
+    }
+
+    /// Reduce each window of the matrix to one value, with the windows as the rows given to `reduce`
+    fn pool2d<F>(
+        &self,
+        pool_rows: usize,
+        pool_cols: usize,
+        stride: usize,
+        reduce: F,
+    ) -> Result<Matrix<T>, MatrixError>
+    where
+        F: Fn(&Matrix<T>) -> Matrix<T>,
+    {
+        let geometry = conv_geometry(
+            (self.rows, self.cols),
+            (pool_rows, pool_cols),
+            stride,
+            Padding::Valid,
+        )?;
+
+        let windows = self.im2col(pool_rows, pool_cols, stride, Padding::Valid)?;
+        let mut result = reduce(&windows);
+
+        result.reshape(geometry.out_rows, geometry.out_cols);
+        Ok(result)
+    }
+}
@@ -0,0 +1,774 @@
+use crate::data_utils::dataframe::ColumnType;
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Write},
+};
+
+/// The characters that separate, quote and escape the fields of a CSV file
+///
+/// The default dialect follows RFC 4180: fields are separated by commas, and fields containing the delimiter,
+/// a quote or a line break are wrapped in double quotes, where a quote inside the field is written twice (`""`).
+/// Read more: <https://www.rfc-editor.org/rfc/rfc4180>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvDialect {
+    /// Character between the fields of a record
+    pub delimiter: char,
+
+    /// Character around fields that contain the delimiter, quotes or line breaks, or `None` to read quotes as text
+    pub quote: Option<char>,
+
+    /// Character in a quoted field that makes the next character literal, such as `\`.
+    /// A quote inside a quoted field can always be written twice, with or without an escape character.
+    pub escape: Option<char>,
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self::new(',')
+    }
+}
+
+impl CsvDialect {
+    /// Create a dialect with the given delimiter, double quotes and no escape character
+    pub fn new(delimiter: char) -> Self {
+        CsvDialect {
+            delimiter,
+            quote: Some('"'),
+            escape: None,
+        }
+    }
+
+    /// Use the given quote character, or `None` to read quotes as text
+    pub fn with_quote(mut self, quote: Option<char>) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Use the given escape character inside quoted fields, or `None` to only allow doubled quotes
+    pub fn with_escape(mut self, escape: Option<char>) -> Self {
+        self.escape = escape;
+        self
+    }
+}
+
+/// Custom Error type for reading CSV records
+///
+/// Line numbers start at 1 and count every line of the input, including lines inside quoted fields.
+#[derive(Debug)]
+pub enum CsvError {
+    /// Reading the input failed, or the input was not valid UTF-8
+    Io(io::Error),
+
+    /// A quoted field that starts at the given line is not closed before the end of the input.
+    /// The field is counted from 1.
+    UnterminatedQuote { line: usize, field: usize },
+
+    /// A quote inside an unquoted field, or text after the closing quote of a field.
+    /// The field is counted from 1.
+    MalformedField { line: usize, field: usize },
+
+    /// A record with a different amount of fields than the first record of the input
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+// For printing the error of reading a CSV record
+impl Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "Could not read the CSV input: {err}"),
+            CsvError::UnterminatedQuote { line, field } => {
+                write!(f, "Line {line}: quoted field {field} is never closed")
+            }
+            CsvError::MalformedField { line, field } => {
+                write!(f, "Line {line}: field {field} has a misplaced quote")
+            }
+            CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => {
+                write!(f, "Line {line}: expected {expected} fields, found {found}")
+            }
+        }
+    }
+}
+
+// Making the CsvError an Error, with the IO error as the source
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+/// A record of a CSV file, and the line it starts at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord {
+    /// Line of the input where the record starts, counted from 1
+    pub line: usize,
+
+    /// The fields of the record, without quotes and escape characters
+    pub fields: Vec<String>,
+
+    /// Whether each field was quoted, so a quoted empty field can be told apart from a missing value
+    pub quoted: Vec<bool>,
+}
+
+/// Where the tokenizer is inside the current field
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldState {
+    /// Nothing of the field is read yet
+    Start,
+
+    /// Inside a field that is not quoted
+    Unquoted,
+
+    /// Inside a quoted field
+    Quoted,
+
+    /// After the escape character inside a quoted field
+    Escaped,
+
+    /// After a quote inside a quoted field, which either closes the field or is the first of a doubled quote
+    QuoteInQuoted,
+}
+
+/// Split a line into its content and its line break (`"\n"`, `"\r\n"` or `""` on the last line)
+fn split_line_break(line: &str) -> (&str, &str) {
+    let content = line
+        .strip_suffix("\r\n")
+        .or_else(|| line.strip_suffix('\n'))
+        .unwrap_or(line);
+    line.split_at(content.len())
+}
+
+/// Reads CSV records from a buffered reader, one record at a time
+///
+/// Quoted fields may contain the delimiter, quotes and line breaks. Empty lines between records are skipped,
+/// and so are comment lines if a comment prefix is set.
+/// Every record must have the same amount of fields as the first record, which is usually the header.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustic_ml::data_utils::csv::{CsvDialect, CsvReader};
+///
+/// let input = "name;comment\nOslo;\"cold; but nice\"\n";
+/// let mut reader = CsvReader::new(input.as_bytes(), CsvDialect::new(';'));
+///
+/// let header = reader.read_record().unwrap().unwrap();
+/// assert_eq!(header.fields, vec!["name", "comment"]);
+///
+/// let record = reader.read_record().unwrap().unwrap();
+/// assert_eq!(record.fields, vec!["Oslo", "cold; but nice"]);
+/// assert_eq!(record.line, 2);
+/// ```
+pub struct CsvReader<R> {
+    reader: R,
+    dialect: CsvDialect,
+    line: usize,
+    field_count: Option<usize>,
+    comment_prefix: Option<String>,
+    buffer: String,
+}
+
+impl<R: BufRead> CsvReader<R> {
+    /// Create a reader of the CSV records in `reader`, using the given dialect
+    pub fn new(reader: R, dialect: CsvDialect) -> Self {
+        CsvReader {
+            reader,
+            dialect,
+            line: 0,
+            field_count: None,
+            comment_prefix: None,
+            buffer: String::new(),
+        }
+    }
+
+    /// Skip lines that start with the given prefix, such as `#`, or `None` to read every line
+    pub fn with_comment_prefix(mut self, comment_prefix: Option<&str>) -> Self {
+        self.comment_prefix = comment_prefix
+            .filter(|prefix| !prefix.is_empty())
+            .map(str::to_owned);
+        self
+    }
+
+    /// Skip the given amount of lines, without splitting them into fields
+    ///
+    /// Used for text before the records, such as a title. Stops at the end of the input.
+    pub fn skip_lines(&mut self, count: usize) -> Result<(), CsvError> {
+        for _ in 0..count {
+            if !self.next_line()? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// The dialect used to split the records
+    pub fn dialect(&self) -> CsvDialect {
+        self.dialect
+    }
+
+    /// Amount of lines read so far
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Read the next line into the buffer, and return false at the end of the input
+    fn next_line(&mut self) -> Result<bool, CsvError> {
+        self.buffer.clear();
+        if self.reader.read_line(&mut self.buffer)? == 0 {
+            return Ok(false);
+        }
+        self.line += 1;
+        Ok(true)
+    }
+
+    /// Read the next record
+    ///
+    /// Returns `Ok(None)` at the end of the input.
+    /// After an error, the lines of the record with the error are skipped, so reading can continue with the next record.
+    /// A misplaced quote is read as text until the end of the record, and the first error of the record is returned.
+    pub fn read_record(&mut self) -> Result<Option<CsvRecord>, CsvError> {
+        // Skip empty lines and comments between records
+        loop {
+            if !self.next_line()? {
+                return Ok(None);
+            }
+            let is_comment = self
+                .comment_prefix
+                .as_deref()
+                .is_some_and(|prefix| self.buffer.starts_with(prefix));
+            if !is_comment && !split_line_break(&self.buffer).0.is_empty() {
+                break;
+            }
+        }
+
+        let start_line = self.line;
+        let mut quote_line = start_line;
+        let mut fields: Vec<String> = Vec::new();
+        let mut quoted: Vec<bool> = Vec::new();
+        let mut field = String::new();
+        let mut field_quoted = false;
+        let mut state = FieldState::Start;
+        let mut error: Option<CsvError> = None;
+        let CsvDialect {
+            delimiter,
+            quote,
+            escape,
+        } = self.dialect;
+        let escape = escape.filter(|&escape| Some(escape) != quote);
+
+        loop {
+            let (content, line_break) = split_line_break(&self.buffer);
+            for c in content.chars() {
+                state = match state {
+                    FieldState::Start | FieldState::Unquoted if c == delimiter => {
+                        fields.push(std::mem::take(&mut field));
+                        quoted.push(std::mem::take(&mut field_quoted));
+                        FieldState::Start
+                    }
+                    FieldState::Start if Some(c) == quote => {
+                        quote_line = self.line;
+                        field_quoted = true;
+                        FieldState::Quoted
+                    }
+                    FieldState::Unquoted if Some(c) == quote => {
+                        error.get_or_insert(CsvError::MalformedField {
+                            line: self.line,
+                            field: fields.len() + 1,
+                        });
+                        field.push(c);
+                        FieldState::Unquoted
+                    }
+                    FieldState::Start | FieldState::Unquoted => {
+                        field.push(c);
+                        FieldState::Unquoted
+                    }
+                    FieldState::Quoted if Some(c) == escape => FieldState::Escaped,
+                    FieldState::Quoted if Some(c) == quote => FieldState::QuoteInQuoted,
+                    FieldState::Quoted | FieldState::Escaped => {
+                        field.push(c);
+                        FieldState::Quoted
+                    }
+                    FieldState::QuoteInQuoted if Some(c) == quote => {
+                        field.push(c);
+                        FieldState::Quoted
+                    }
+                    FieldState::QuoteInQuoted if c == delimiter => {
+                        fields.push(std::mem::take(&mut field));
+                        quoted.push(std::mem::take(&mut field_quoted));
+                        FieldState::Start
+                    }
+                    FieldState::QuoteInQuoted => {
+                        error.get_or_insert(CsvError::MalformedField {
+                            line: self.line,
+                            field: fields.len() + 1,
+                        });
+                        field.push(c);
+                        FieldState::Unquoted
+                    }
+                };
+            }
+
+            // A line break inside a quoted field is part of the field, otherwise it ends the record
+            match state {
+                FieldState::Quoted | FieldState::Escaped => {
+                    field.push_str(line_break);
+                    state = FieldState::Quoted;
+                    if !self.next_line()? {
+                        return Err(error.unwrap_or(CsvError::UnterminatedQuote {
+                            line: quote_line,
+                            field: fields.len() + 1,
+                        }));
+                    }
+                }
+                _ => break,
+            }
+        }
+        fields.push(field);
+        quoted.push(field_quoted);
+
+        // The whole record is read, so the next call starts at the next record
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        // The first record decides the amount of fields
+        let expected = *self.field_count.get_or_insert(fields.len());
+        if fields.len() != expected {
+            return Err(CsvError::RaggedRow {
+                line: start_line,
+                expected,
+                found: fields.len(),
+            });
+        }
+
+        Ok(Some(CsvRecord {
+            line: start_line,
+            fields,
+            quoted,
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for CsvReader<R> {
+    type Item = Result<CsvRecord, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// Options for reading a CSV file into a `Dataframe`
+///
+/// The default options read a header, separate the fields with commas, infer the type of each column,
+/// and read empty fields in number and boolean columns as missing values.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustic_ml::data_utils::csv::CsvReadOptions;
+/// use rustic_ml::data_utils::dataframe::ColumnType;
+///
+/// // European data with comma decimals, where NA and ? are missing values
+/// let options = CsvReadOptions::new()
+///     .with_delimiter(';')
+///     .with_decimal_separator(',')
+///     .with_null_tokens(&["NA", "?"])
+///     .with_column_type("zip_code", ColumnType::Text)
+///     .with_comment_prefix("#")
+///     .with_max_rows(1000);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvReadOptions {
+    /// The characters used to separate, quote and escape the fields
+    pub dialect: CsvDialect,
+
+    /// The first record is the header with the column names.
+    /// Without a header, the columns are named `column_0`, `column_1` and so on, unless a schema is given.
+    pub has_header: bool,
+
+    /// Names and types of every column, replacing the header and type inference
+    pub schema: Option<Vec<(String, ColumnType)>>,
+
+    /// Types of single columns by name, replacing type inference for those columns
+    pub column_types: Vec<(String, ColumnType)>,
+
+    /// Fields that are read as missing values in every column, such as `NA` or `null`. Quoted fields are never missing
+    pub null_tokens: Vec<String>,
+
+    /// Amount of lines to skip before the header or the first record
+    pub skip_rows: usize,
+
+    /// Largest amount of records to read after the header, or `None` to read all records
+    pub max_rows: Option<usize>,
+
+    /// Lines starting with this prefix are skipped
+    pub comment_prefix: Option<String>,
+
+    /// Remove whitespace around every field, including text fields and column names.
+    /// Numbers and booleans are always read without the whitespace around them.
+    pub trim: bool,
+
+    /// Character between the integer and fractional part of floats, such as `,` in European data
+    pub decimal_separator: char,
+
+    /// Amount of records used to infer the column types, or `None` to use every record.
+    /// When reading in chunks, `None` infers the types from the first chunk.
+    pub infer_rows: Option<usize>,
+}
+
+impl Default for CsvReadOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvReadOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        CsvReadOptions {
+            dialect: CsvDialect::default(),
+            has_header: true,
+            schema: None,
+            column_types: Vec::new(),
+            null_tokens: Vec::new(),
+            skip_rows: 0,
+            max_rows: None,
+            comment_prefix: None,
+            trim: false,
+            decimal_separator: '.',
+            infer_rows: None,
+        }
+    }
+
+    /// Separate the fields with the given delimiter
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.dialect.delimiter = delimiter;
+        self
+    }
+
+    /// Use the given dialect for the delimiter, quote and escape characters
+    pub fn with_dialect(mut self, dialect: CsvDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Read the first record as the header or as data
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Use the given names and types for the columns, in the order of the file
+    pub fn with_schema(mut self, schema: &[(&str, ColumnType)]) -> Self {
+        let schema = schema
+            .iter()
+            .map(|&(name, column_type)| (name.to_owned(), column_type))
+            .collect();
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Read the column with the given name as the given type
+    pub fn with_column_type(mut self, column_name: &str, column_type: ColumnType) -> Self {
+        self.column_types.retain(|(name, _)| name != column_name);
+        self.column_types
+            .push((column_name.to_owned(), column_type));
+        self
+    }
+
+    /// Read fields that are equal to one of the tokens as missing values
+    ///
+    /// Quoted fields are read as text, so `""` is an empty text even if the empty token is given.
+    pub fn with_null_tokens(mut self, null_tokens: &[&str]) -> Self {
+        self.null_tokens = null_tokens.iter().map(|&token| token.to_owned()).collect();
+        self
+    }
+
+    /// Skip the given amount of lines at the start of the file
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Read at most the given amount of records after the header
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Skip lines that start with the given prefix
+    pub fn with_comment_prefix(mut self, comment_prefix: &str) -> Self {
+        self.comment_prefix = Some(comment_prefix.to_owned());
+        self
+    }
+
+    /// Remove whitespace around every field or not
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Read floats with the given decimal separator
+    pub fn with_decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Infer the column types from the first records, instead of every record
+    ///
+    /// Later values that do not fit the inferred type are parse errors. A column with only empty values in these records is text, unless they are all the records.
+    pub fn with_infer_rows(mut self, infer_rows: usize) -> Self {
+        self.infer_rows = Some(infer_rows);
+        self
+    }
+}
+
+/// When the fields of a written record are wrapped in quotes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+    /// Only quote fields that contain the delimiter, the quote or escape character, or a line break
+    #[default]
+    Necessary,
+
+    /// Quote every field
+    Always,
+
+    /// Never quote fields, even when they can not be read back
+    Never,
+}
+
+/// Options for writing a table as CSV
+///
+/// The default options write a header, separate the fields with commas, quote the fields that need it,
+/// and write missing values as empty fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustic_ml::data_utils::csv::{CsvWriteOptions, QuoteStyle};
+///
+/// // Tab separated values, without a header and with NA for missing values
+/// let options = CsvWriteOptions::new()
+///     .with_delimiter('\t')
+///     .with_header(false)
+///     .with_null_value("NA")
+///     .with_quote_style(QuoteStyle::Necessary);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvWriteOptions {
+    /// The characters used to separate and quote the fields
+    pub dialect: CsvDialect,
+
+    /// When fields are quoted
+    pub quote_style: QuoteStyle,
+
+    /// The text written for missing values
+    pub null_value: String,
+
+    /// Write the column names as the first record
+    pub has_header: bool,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvWriteOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        CsvWriteOptions {
+            dialect: CsvDialect::default(),
+            quote_style: QuoteStyle::Necessary,
+            null_value: String::new(),
+            has_header: true,
+        }
+    }
+
+    /// Separate the fields with the given delimiter
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.dialect.delimiter = delimiter;
+        self
+    }
+
+    /// Use the given dialect for the delimiter, quote and escape characters
+    pub fn with_dialect(mut self, dialect: CsvDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Quote fields with the given style
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Write missing values as the given text
+    pub fn with_null_value(mut self, null_value: &str) -> Self {
+        self.null_value = null_value.to_owned();
+        self
+    }
+
+    /// Write the column names as the first record or not
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+}
+
+/// Writes CSV records to a writer, quoting and escaping the fields so they can be read by `CsvReader`
+///
+/// Quotes inside a quoted field are written twice (`""`), or after the escape character if the dialect has one.
+/// Records end with `\n`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustic_ml::data_utils::csv::{CsvDialect, CsvWriter, QuoteStyle};
+///
+/// let mut writer = CsvWriter::new(Vec::new(), CsvDialect::new(';'), QuoteStyle::Necessary);
+/// writer.write_record(["name", "comment"]).unwrap();
+/// writer.write_record(["Oslo", "cold; but nice"]).unwrap();
+///
+/// let output = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(output, "name;comment\nOslo;\"cold; but nice\"\n");
+/// ```
+pub struct CsvWriter<W> {
+    writer: W,
+    dialect: CsvDialect,
+    quote_style: QuoteStyle,
+    buffer: String,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Create a writer of CSV records to `writer`, using the given dialect and quote style
+    pub fn new(writer: W, dialect: CsvDialect, quote_style: QuoteStyle) -> Self {
+        CsvWriter {
+            writer,
+            dialect,
+            quote_style,
+            buffer: String::new(),
+        }
+    }
+
+    /// Check if the field must be quoted to be read back as the same text
+    fn needs_quotes(&self, field: &str) -> bool {
+        field.chars().any(|c| {
+            c == self.dialect.delimiter
+                || c == '\n'
+                || c == '\r'
+                || Some(c) == self.dialect.quote
+                || Some(c) == self.dialect.escape
+        })
+    }
+
+    /// Write one record, with the fields separated by the delimiter
+    pub fn write_record<I, S>(&mut self, fields: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.buffer.clear();
+        let mut count = 0;
+        for field in fields {
+            self.push_field(count, field.as_ref(), false);
+            count += 1;
+        }
+        self.finish_record(count)
+    }
+
+    /// Write one record where `None` fields are missing values, written as `null_value`
+    ///
+    /// A field with the same text as `null_value` is quoted with `QuoteStyle::Necessary`,
+    /// so it is not read as a missing value when `null_value` is a null token of `CsvReadOptions`.
+    pub fn write_record_with_nulls<I, S>(&mut self, fields: I, null_value: &str) -> io::Result<()>
+    where
+        I: IntoIterator<Item = Option<S>>,
+        S: AsRef<str>,
+    {
+        self.buffer.clear();
+        let mut count = 0;
+        for field in fields {
+            match field {
+                Some(field) => {
+                    let field = field.as_ref();
+                    self.push_field(count, field, field == null_value);
+                }
+                None => self.push_field(count, null_value, false),
+            }
+            count += 1;
+        }
+        self.finish_record(count)
+    }
+
+    /// Add a field to the record in the buffer, quoted if the quote style requires it or `force_quote` is set
+    fn push_field(&mut self, index: usize, field: &str, force_quote: bool) {
+        if index > 0 {
+            self.buffer.push(self.dialect.delimiter);
+        }
+
+        let quote = match (self.dialect.quote, self.quote_style) {
+            (Some(quote), QuoteStyle::Always) => Some(quote),
+            (Some(quote), QuoteStyle::Necessary) if force_quote || self.needs_quotes(field) => {
+                Some(quote)
+            }
+            _ => None,
+        };
+        let Some(quote) = quote else {
+            self.buffer.push_str(field);
+            return;
+        };
+
+        // Escape the quotes, and the escape characters so they are not read as escapes
+        let escape = self.dialect.escape.unwrap_or(quote);
+        self.buffer.push(quote);
+        for c in field.chars() {
+            if c == quote || c == escape {
+                self.buffer.push(escape);
+            }
+            self.buffer.push(c);
+        }
+        self.buffer.push(quote);
+    }
+
+    /// End the record in the buffer with a line break, and write it
+    fn finish_record(&mut self, count: usize) -> io::Result<()> {
+        // A record with a single empty field would be an empty line, which is skipped when reading
+        if count == 1 && self.buffer.is_empty() {
+            if let (Some(quote), QuoteStyle::Necessary) = (self.dialect.quote, self.quote_style) {
+                self.buffer.push(quote);
+                self.buffer.push(quote);
+            }
+        }
+
+        self.buffer.push('\n');
+        self.writer.write_all(self.buffer.as_bytes())
+    }
+
+    /// Flush the underlying writer
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Get the underlying writer back
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -1,13 +1,22 @@
 use super::datacolumn::DataColumnTrait;
+use crate::data_utils::csv::{
+    CsvDialect, CsvError, CsvReadOptions, CsvReader, CsvRecord, CsvWriteOptions, CsvWriter,
+};
 use crate::data_utils::datacolumn::DataColumn;
-use std::fs;
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    str::FromStr,
+    vec::IntoIter,
+};
 
 /// A  enumeration type that represents different types of columns that can be present in a dataset.
 ///
 /// The variants of this enum are `Integer`, `Float`, `Boolean`, and
 /// `Text`, which correspond to the possible data types that a column can have. This enum is used in the
 /// `Dataframe` struct to infer the type of data present in each column when reading data from a file.
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum ColumnType {
     Integer,
     Float,
@@ -15,6 +24,117 @@ pub enum ColumnType {
     Text,
 }
 
+// For printing the name of a column type
+impl Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnType::Integer => write!(f, "Integer"),
+            ColumnType::Float => write!(f, "Float"),
+            ColumnType::Boolean => write!(f, "Boolean"),
+            ColumnType::Text => write!(f, "Text"),
+        }
+    }
+}
+
+/// Custom Error type for reading, writing and changing a `Dataframe`
+#[derive(Debug)]
+pub enum DataframeError {
+    /// Reading or writing failed, for example when the file does not exist
+    Io(io::Error),
+
+    /// The file is malformed at the given row and column.
+    /// The row is the line of the file and the column is the field of the record, both counted from 1.
+    Parse {
+        row: usize,
+        col: usize,
+        message: String,
+    },
+
+    /// There is no column with the given name or index
+    MissingColumn(String),
+
+    /// A list of values does not have the same length as the columns of the `Dataframe`
+    LengthMismatch { expected: usize, found: usize },
+
+    /// The column has another type than the one that is required
+    TypeMismatch {
+        column: String,
+        expected: ColumnType,
+        found: ColumnType,
+    },
+}
+
+// For printing the error of a dataframe
+impl Display for DataframeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataframeError::Io(err) => {
+                write!(f, "Could not read or write the dataframe: {err}")
+            }
+            DataframeError::Parse { row, col, message } => {
+                write!(f, "Could not parse row {row}, column {col}: {message}")
+            }
+            DataframeError::MissingColumn(column) => {
+                write!(f, "The dataframe has no column {column}")
+            }
+            DataframeError::LengthMismatch { expected, found } => {
+                write!(f, "Expected {expected} values, found {found}")
+            }
+            DataframeError::TypeMismatch {
+                column,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "The column {column} has type {found}, but type {expected} is required"
+                )
+            }
+        }
+    }
+}
+
+// Making the DataframeError an Error, with the IO error as the source
+impl std::error::Error for DataframeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataframeError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DataframeError {
+    fn from(err: io::Error) -> Self {
+        DataframeError::Io(err)
+    }
+}
+
+impl From<CsvError> for DataframeError {
+    fn from(err: CsvError) -> Self {
+        let message = err.to_string();
+        match err {
+            CsvError::Io(err) => DataframeError::Io(err),
+            CsvError::UnterminatedQuote { line, field }
+            | CsvError::MalformedField { line, field } => DataframeError::Parse {
+                row: line,
+                col: field,
+                message,
+            },
+            // The first field that is missing or extra
+            CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => DataframeError::Parse {
+                row: line,
+                col: expected.min(found) + 1,
+                message,
+            },
+        }
+    }
+}
+
 /// `DataColumnEnum` enum is used to represent different types of `DataColumn` instances.
 ///
 /// Each variant of the enum corresponds to a specific type of data column
@@ -64,28 +184,32 @@ impl Dataframe {
     /// you want to read from.
     ///
     /// # Errors:
-    /// - When file is not found, path was not correct
+    /// - `DataframeError::Io` when file is not found, path was not correct
+    /// - `DataframeError::Parse` when a row is malformed, or has a different amount of fields than the header
     ///
     /// # Returns:
     ///
     /// The `from_csv` function is returning a `Result` containing either an instance of the struct it
-    /// belongs to (represented by `Self`) or an empty tuple `()`.
-    pub fn from_csv(path: String) -> Result<Self, ()> {
+    /// belongs to (represented by `Self`) or a `DataframeError`.
+    pub fn from_csv(path: String) -> Result<Self, DataframeError> {
         Self::from_file(path, ';')
     }
 
     /// Get the `ColumnType` of a given list of data.
     ///
     /// Will check the whole column, and determine its data based on what it was able to cast to.
-    fn infer_column_type(column_data: &[String]) -> ColumnType {
+    /// A column with only empty values gets the `empty_type`.
+    fn infer_column_type(column_data: &[String], empty_type: ColumnType) -> ColumnType {
         let mut is_integer = true;
         let mut is_float = true;
         let mut is_boolean = true;
+        let mut has_value = false;
 
         for value in column_data {
             if value.is_empty() {
                 continue; // Skip empty values
             }
+            has_value = true;
 
             if is_integer && value.parse::<i32>().is_err() {
                 is_integer = false;
@@ -106,7 +230,9 @@ impl Dataframe {
         }
 
         // Decide the type based on what was true
-        if is_integer {
+        if !has_value {
+            empty_type
+        } else if is_integer {
             ColumnType::Integer
         } else if is_float {
             ColumnType::Float
@@ -168,13 +294,24 @@ impl Dataframe {
 
     /// Reads data from a  file using the given delimiter, and creates a `Dataframe`
     ///
+    /// Fields may be quoted with double quotes, so they can contain the delimiter, line breaks and doubled quotes (`""`).
+    /// Use `from_file_with_dialect` for other quote and escape characters.
+    ///
+    /// Every row must have as many fields as the header. A name that contains the delimiter must be quoted,
+    /// otherwise it is split into several fields and the first row is reported as malformed.
+    /// For example `european_cities.txt` has the unquoted name `Saint Petersburg`, and fails to read at line 2.
+    ///
     /// # Examples
     ///
     /// ```rust
     /// use rustic_ml::data_utils::dataframe::Dataframe;
     ///
-    /// let path = String::from("./datasets/european_cities.txt");
+    /// let path = String::from("./datasets/european_cities_quoted.txt");
     /// let dataframe = Dataframe::from_file(path, ' ').unwrap();
+    ///
+    /// // The header splits `Saint Petersburg` into two names
+    /// let path = String::from("./datasets/european_cities.txt");
+    /// assert!(Dataframe::from_file(path, ' ').is_err());
     /// ```
     ///
     /// # Arguments:
@@ -184,126 +321,583 @@ impl Dataframe {
     /// - 'delimiter': The delimiter that septate records
     ///
     /// # Errors:
-    /// - When file is not found, path was not correct
+    /// - `DataframeError::Io` when file is not found, path was not correct
+    /// - `DataframeError::Parse` when a row is malformed, or has a different amount of fields than the header
     ///
     /// # Returns:
     ///
-    /// The `from_csv` function is returning a `Result` containing either an instance of the struct it
-    /// belongs to (represented by `Self`) or an empty tuple `()`.    
-    pub fn from_file(path: String, delimiter: char) -> Result<Self, ()> {
-        // Read the file
-        let contents = match fs::read_to_string(&path) {
-            Ok(val) => val,
-            Err(_) => {
-                println!("ERROR: could not read csv file: {}", path);
-                return Err(());
-            }
+    /// The `from_file` function is returning a `Result` containing either an instance of the struct it
+    /// belongs to (represented by `Self`) or a `DataframeError`.
+    pub fn from_file(path: String, delimiter: char) -> Result<Self, DataframeError> {
+        Self::from_file_with_dialect(path, CsvDialect::new(delimiter))
+    }
+
+    /// Reads data from a file using the given `CsvDialect`, and creates a `Dataframe`
+    ///
+    /// The first record is the header with the column names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::csv::CsvDialect;
+    /// use rustic_ml::data_utils::dataframe::Dataframe;
+    ///
+    /// let path = String::from("./datasets/european_cities.csv");
+    /// let dialect = CsvDialect::new(';').with_escape(Some('\\'));
+    /// let dataframe = Dataframe::from_file_with_dialect(path, dialect).unwrap();
+    /// assert!(dataframe.has_column("Barcelona"));
+    /// ```
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when file is not found, path was not correct
+    /// - `DataframeError::Parse` when the file is empty, a row is malformed, or has a different amount of fields than the header
+    pub fn from_file_with_dialect(
+        path: String,
+        dialect: CsvDialect,
+    ) -> Result<Self, DataframeError> {
+        Self::from_file_with_options(path, &CsvReadOptions::new().with_dialect(dialect))
+    }
+
+    /// Reads data from a file using the given `CsvReadOptions`, and creates a `Dataframe`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::csv::CsvReadOptions;
+    /// use rustic_ml::data_utils::dataframe::{ColumnType, Dataframe};
+    ///
+    /// let path = String::from("./datasets/european_cities.csv");
+    /// let options = CsvReadOptions::new()
+    ///     .with_delimiter(';')
+    ///     .with_column_type("Barcelona", ColumnType::Text)
+    ///     .with_max_rows(10);
+    /// let dataframe = Dataframe::from_file_with_options(path, &options).unwrap();
+    ///
+    /// assert_eq!(dataframe.get_column_type("Barcelona").unwrap(), ColumnType::Text);
+    /// assert_eq!(dataframe.at_str("Barcelona", 9).unwrap(), Some("2230.42".to_string()));
+    /// assert_eq!(dataframe.at_str("Barcelona", 10).unwrap(), None);
+    /// ```
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when file is not found, path was not correct
+    /// - the errors of `from_reader`
+    pub fn from_file_with_options(
+        path: String,
+        options: &CsvReadOptions,
+    ) -> Result<Self, DataframeError> {
+        let file = File::open(&path)?;
+        Self::from_reader(BufReader::new(file), options)
+    }
+
+    /// Reads CSV data from any buffered reader using the given `CsvReadOptions`, and creates a `Dataframe`
+    ///
+    /// Columns without a type in the schema or the column types are inferred from their values.
+    /// A column where every value is empty is an integer column of missing values.
+    /// If only the first `infer_rows` records are used, a column that is empty in them is a text column instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::csv::CsvReadOptions;
+    /// use rustic_ml::data_utils::dataframe::{ColumnType, Dataframe};
+    ///
+    /// let input = "# Exported data\nid;price\n1;2,50\n2;NA\n";
+    /// let options = CsvReadOptions::new()
+    ///     .with_delimiter(';')
+    ///     .with_comment_prefix("#")
+    ///     .with_null_tokens(&["NA"])
+    ///     .with_decimal_separator(',');
+    /// let dataframe = Dataframe::from_reader(input.as_bytes(), &options).unwrap();
+    ///
+    /// assert_eq!(dataframe.get_column_type("price").unwrap(), ColumnType::Float);
+    /// assert_eq!(dataframe.at_str("price", 0).unwrap(), Some("2.5".to_string()));
+    /// assert_eq!(dataframe.at_str("price", 1).unwrap(), None);
+    /// ```
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when reading failed
+    /// - `DataframeError::Parse` when the header is missing, a row is malformed, or has a different amount of fields than the header,
+    ///   or a value can not be parsed as the type given in the options
+    /// - `DataframeError::LengthMismatch` when the schema does not have a column for every field
+    /// - `DataframeError::MissingColumn` when a column type is given for a column that does not exist
+    pub fn from_reader<R: BufRead>(
+        reader: R,
+        options: &CsvReadOptions,
+    ) -> Result<Self, DataframeError> {
+        let (mut reader, header) = Self::open_csv(reader, options)?;
+        let records = Self::read_records(&mut reader, options.max_rows.unwrap_or(usize::MAX))?;
+
+        let (column_names, column_types) = Self::resolve_schema(header, &records, options)?;
+        let sample_size = options
+            .infer_rows
+            .unwrap_or(records.len())
+            .min(records.len());
+        // Records after the sample may have any value in a column that is empty in the sample
+        let empty_type = if sample_size < records.len() {
+            ColumnType::Text
+        } else {
+            ColumnType::Integer
         };
+        let column_types =
+            Self::infer_column_types(column_types, &records[..sample_size], empty_type, options);
 
-        // Collect to a vector of lines
-        let csv_lines: Vec<_> = contents.lines().collect();
+        let rows_count = records.len();
+        let columns = Self::columns_from_records(column_names, &column_types, records, options)?;
+        Ok(Dataframe {
+            columns,
+            rows_count: rows_count as u32,
+        })
+    }
 
-        // Count how many columns there are
-        let column_count: usize = csv_lines[0].split(delimiter).count();
+    /// Reads a CSV file in chunks, and returns an iterator over a `Dataframe` for each chunk of `chunk_size` rows
+    ///
+    /// The file is read one chunk at a time, so files larger than the memory can be processed.
+    /// See `chunks_from_reader` for how the column types are decided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::csv::CsvReadOptions;
+    /// use rustic_ml::data_utils::dataframe::Dataframe;
+    ///
+    /// let path = String::from("./datasets/european_cities.csv");
+    /// let options = CsvReadOptions::new().with_delimiter(';');
+    ///
+    /// let mut rows = 0;
+    /// for chunk in Dataframe::chunks_from_file(path, &options, 10).unwrap() {
+    ///     let chunk = chunk.unwrap();
+    ///     assert!(chunk.has_column("Barcelona"));
+    ///     rows += chunk.row_count();
+    /// }
+    /// assert_eq!(rows, 24);
+    /// ```
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when file is not found, path was not correct
+    /// - the errors of `chunks_from_reader`
+    pub fn chunks_from_file(
+        path: String,
+        options: &CsvReadOptions,
+        chunk_size: usize,
+    ) -> Result<DataframeChunks<BufReader<File>>, DataframeError> {
+        let file = File::open(&path)?;
+        Self::chunks_from_reader(BufReader::new(file), options, chunk_size)
+    }
+
+    /// Reads CSV data from any buffered reader in chunks, and returns an iterator over a `Dataframe` for each chunk of `chunk_size` rows
+    ///
+    /// The column types are decided before the first chunk, from the schema and the column types of the options,
+    /// or inferred from the first `infer_rows` records. Without `infer_rows`, the types are inferred from the first chunk.
+    /// A column that is empty in these records is a text column, since any value may follow.
+    /// Every chunk has the same column types, and a later value that does not fit the type is a `DataframeError::Parse`.
+    /// A chunk size of 0 is read as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::csv::CsvReadOptions;
+    /// use rustic_ml::data_utils::dataframe::{ColumnType, Dataframe};
+    ///
+    /// let input = "id;value\n1;1\n2;2.5\n3;4\n";
+    /// let options = CsvReadOptions::new().with_delimiter(';').with_infer_rows(2);
+    /// let chunks: Vec<Dataframe> = Dataframe::chunks_from_reader(input.as_bytes(), &options, 2)
+    ///     .unwrap()
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(chunks[1].row_count(), 1);
+    /// assert_eq!(chunks[1].get_column_type("value").unwrap(), ColumnType::Float);
+    /// ```
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when reading failed
+    /// - `DataframeError::Parse` when the header is missing, or a record in the sample is malformed
+    /// - `DataframeError::LengthMismatch` when the schema does not have a column for every field
+    /// - `DataframeError::MissingColumn` when a column type is given for a column that does not exist
+    ///
+    /// The iterator returns the errors of reading the later chunks, and stops after the first error.
+    pub fn chunks_from_reader<R: BufRead>(
+        reader: R,
+        options: &CsvReadOptions,
+        chunk_size: usize,
+    ) -> Result<DataframeChunks<R>, DataframeError> {
+        let chunk_size = chunk_size.max(1);
+        let max_rows = options.max_rows.unwrap_or(usize::MAX);
+
+        let (mut reader, header) = Self::open_csv(reader, options)?;
+        let sample_size = options.infer_rows.unwrap_or(chunk_size).min(max_rows);
+        let sample = Self::read_records(&mut reader, sample_size)?;
+
+        let (column_names, column_types) = Self::resolve_schema(header, &sample, options)?;
+        // Later chunks may have any value in a column that is empty in the sample
+        let column_types =
+            Self::infer_column_types(column_types, &sample, ColumnType::Text, options);
+
+        Ok(DataframeChunks {
+            rows_left: max_rows - sample.len(),
+            reader,
+            options: options.clone(),
+            column_names,
+            column_types,
+            chunk_size,
+            sample: sample.into_iter(),
+            done: false,
+        })
+    }
+
+    /// Create a `CsvReader` with the options, skip the first rows and read the header, if the options have one
+    fn open_csv<R: BufRead>(
+        reader: R,
+        options: &CsvReadOptions,
+    ) -> Result<(CsvReader<R>, Option<Vec<String>>), DataframeError> {
+        let mut reader = CsvReader::new(reader, options.dialect)
+            .with_comment_prefix(options.comment_prefix.as_deref());
+        reader.skip_lines(options.skip_rows)?;
+
+        if !options.has_header {
+            return Ok((reader, None));
+        }
+
+        match reader.read_record()? {
+            Some(record) => Ok((reader, Some(record.fields))),
+            None => Err(DataframeError::Parse {
+                row: reader.line() + 1,
+                col: 1,
+                message: "The file has no header".to_string(),
+            }),
+        }
+    }
+
+    /// Read at most `count` records
+    fn read_records<R: BufRead>(
+        reader: &mut CsvReader<R>,
+        count: usize,
+    ) -> Result<Vec<CsvRecord>, DataframeError> {
+        let mut records: Vec<CsvRecord> = Vec::new();
+        while records.len() < count {
+            match reader.read_record()? {
+                Some(record) => records.push(record),
+                None => break,
+            }
+        }
+        Ok(records)
+    }
 
-        // Column names for the dataset
-        let column_names: Vec<_> = csv_lines[0].split(delimiter).collect();
+    /// Get the names of the columns, and the types that are given by the options
+    fn resolve_schema(
+        header: Option<Vec<String>>,
+        records: &[CsvRecord],
+        options: &CsvReadOptions,
+    ) -> Result<(Vec<String>, Vec<Option<ColumnType>>), DataframeError> {
+        let field_count = header
+            .as_ref()
+            .or(records.first().map(|record| &record.fields))
+            .map(|fields| fields.len());
+        let column_names = Self::resolve_column_names(header, field_count, options)?;
+        let column_types = Self::resolve_column_types(&column_names, options)?;
+        Ok((column_names, column_types))
+    }
 
-        // Get the data types for each column and initialize each column
-        let columns_with_data: Vec<_> = csv_lines[1].split(delimiter).collect();
+    /// Get the names of the columns from the schema, the header, or as `column_0`, `column_1` and so on
+    fn resolve_column_names(
+        header: Option<Vec<String>>,
+        field_count: Option<usize>,
+        options: &CsvReadOptions,
+    ) -> Result<Vec<String>, DataframeError> {
+        if let Some(schema) = &options.schema {
+            match field_count {
+                Some(count) if count != schema.len() => {
+                    return Err(DataframeError::LengthMismatch {
+                        expected: count,
+                        found: schema.len(),
+                    })
+                }
+                _ => return Ok(schema.iter().map(|(name, _)| name.clone()).collect()),
+            }
+        }
 
-        // Create the vector of column data
-        let mut dataframe_columns: Vec<DataColumnEnum> = Vec::with_capacity(column_count);
+        let names = match header {
+            Some(header) if options.trim => header
+                .into_iter()
+                .map(|name| name.trim().to_string())
+                .collect(),
+            Some(header) => header,
+            None => (0..field_count.unwrap_or(0))
+                .map(|index| format!("column_{index}"))
+                .collect(),
+        };
+        Ok(names)
+    }
 
-        for (index, _) in columns_with_data.iter().enumerate() {
-            // Gather all data in this column as a vector of items
-            let column_data: Vec<_> = csv_lines
+    /// Get the type of each column from the schema or the column types, or `None` if it is inferred
+    fn resolve_column_types(
+        column_names: &[String],
+        options: &CsvReadOptions,
+    ) -> Result<Vec<Option<ColumnType>>, DataframeError> {
+        if let Some(schema) = &options.schema {
+            return Ok(schema
                 .iter()
-                .skip(1)
-                .map(|line| {
-                    let values = line.split(delimiter).collect::<Vec<_>>();
-                    values[index].trim().to_string() // Trim the value
-                })
-                .collect();
+                .map(|&(_, column_type)| Some(column_type))
+                .collect());
+        }
 
-            // Get the column type
-            let column_type = Self::infer_column_type(&column_data);
+        // Every column type must belong to a column
+        if let Some((name, _)) = options
+            .column_types
+            .iter()
+            .find(|(name, _)| !column_names.contains(name))
+        {
+            return Err(DataframeError::MissingColumn(name.clone()));
+        }
 
-            match column_type {
-                ColumnType::Integer => {
-                    // Collect all data for the given column
-                    let data_vec: Vec<Option<i32>> = csv_lines
-                        .iter()
-                        .skip(1)
-                        .map(|line| {
-                            let value = line.split(delimiter).collect::<Vec<_>>()[index];
-                            match value.parse::<i32>() {
-                                Ok(parsed_val) => Some(parsed_val),
-                                Err(_) => None, // Handle non-integer values as None
-                            }
-                        })
-                        .collect();
+        Ok(column_names
+            .iter()
+            .map(|column_name| {
+                options
+                    .column_types
+                    .iter()
+                    .find(|(name, _)| name == column_name)
+                    .map(|&(_, column_type)| column_type)
+            })
+            .collect())
+    }
 
-                    let new_column = DataColumn::new(data_vec, column_names[index].to_owned());
-                    dataframe_columns.push(DataColumnEnum::IntColumn(new_column));
-                }
-                ColumnType::Float => {
-                    let data_vec: Vec<Option<f32>> = csv_lines
-                        .iter()
-                        .skip(1)
-                        .map(|line| {
-                            let value = line.split(delimiter).collect::<Vec<_>>()[index];
-                            match value.parse::<f32>() {
-                                Ok(parsed_val) => Some(parsed_val),
-                                Err(_) => None, // Handle non-float values as None
-                            }
-                        })
-                        .collect();
+    /// Check if a field of the record is one of the null tokens, compared without the whitespace around it
+    ///
+    /// A quoted field is never a null token, so `""` is an empty text while an empty field may be missing.
+    fn is_null_token(record: &CsvRecord, index: usize, options: &CsvReadOptions) -> bool {
+        let value = record.fields[index].trim();
+        !record.quoted[index] && options.null_tokens.iter().any(|token| token == value)
+    }
 
-                    let new_column = DataColumn::new(data_vec, column_names[index].to_owned());
-                    dataframe_columns.push(DataColumnEnum::FloatColumn(new_column));
-                }
-                ColumnType::Boolean => {
-                    let data_vec: Vec<Option<bool>> = csv_lines
+    /// The value of a field as it is parsed, without whitespace around it and with a point as the decimal separator
+    fn normalize_value(value: &str, options: &CsvReadOptions) -> String {
+        let value = value.trim();
+        if options.decimal_separator == '.' {
+            value.to_string()
+        } else {
+            value.replace(options.decimal_separator, ".")
+        }
+    }
+
+    /// Infer the type of the columns without a type from the values in the records
+    ///
+    /// Columns that only have empty values in the records get the `empty_type`.
+    fn infer_column_types(
+        column_types: Vec<Option<ColumnType>>,
+        records: &[CsvRecord],
+        empty_type: ColumnType,
+        options: &CsvReadOptions,
+    ) -> Vec<ColumnType> {
+        column_types
+            .into_iter()
+            .enumerate()
+            .map(|(index, column_type)| {
+                column_type.unwrap_or_else(|| {
+                    let normalized: Vec<String> = records
                         .iter()
-                        .skip(1)
-                        .map(|line| {
-                            let value = line.split(delimiter).collect::<Vec<_>>()[index];
-                            match value.parse::<bool>() {
-                                Ok(parsed_val) => Some(parsed_val),
-                                Err(_) => None, // Handle non-boolean values as None
+                        .map(|record| {
+                            if Self::is_null_token(record, index, options) {
+                                String::new()
+                            } else {
+                                Self::normalize_value(&record.fields[index], options)
                             }
                         })
                         .collect();
+                    Self::infer_column_type(&normalized, empty_type)
+                })
+            })
+            .collect()
+    }
 
-                    let new_column = DataColumn::new(data_vec, column_names[index].to_owned());
-                    dataframe_columns.push(DataColumnEnum::BoolColumn(new_column));
-                }
-                ColumnType::Text => {
-                    let data_vec: Vec<Option<String>> = csv_lines
-                        .iter()
-                        .skip(1)
-                        .map(|line| {
-                            let value =
-                                line.split(delimiter).collect::<Vec<_>>()[index].to_string();
+    /// Create the columns of the given types from the fields of the records
+    fn columns_from_records(
+        column_names: Vec<String>,
+        column_types: &[ColumnType],
+        mut records: Vec<CsvRecord>,
+        options: &CsvReadOptions,
+    ) -> Result<Vec<DataColumnEnum>, DataframeError> {
+        let lines: Vec<usize> = records.iter().map(|record| record.line).collect();
+
+        column_names
+            .into_iter()
+            .zip(column_types)
+            .enumerate()
+            .map(|(index, (name, &column_type))| {
+                // Null tokens are missing values
+                let values: Vec<Option<String>> = records
+                    .iter_mut()
+                    .map(|record| {
+                        let is_null = Self::is_null_token(record, index, options);
+                        let value = std::mem::take(&mut record.fields[index]);
+                        if is_null {
+                            None
+                        } else if options.trim {
+                            Some(value.trim().to_string())
+                        } else {
                             Some(value)
-                        })
-                        .collect();
+                        }
+                    })
+                    .collect();
+                Self::column_from_values(name, values, &lines, index, column_type, options)
+            })
+            .collect()
+    }
+
+    /// Create a column of the given `ColumnType` from the values as text
+    ///
+    /// Numbers and booleans are parsed without the whitespace around them, and empty values are `None`.
+    /// Returns `DataframeError::Parse` if a value can not be parsed as the column type.
+    fn column_from_values(
+        name: String,
+        values: Vec<Option<String>>,
+        lines: &[usize],
+        index: usize,
+        column_type: ColumnType,
+        options: &CsvReadOptions,
+    ) -> Result<DataColumnEnum, DataframeError> {
+        let normalized: Vec<String> = values
+            .iter()
+            .map(|value| Self::normalize_value(value.as_deref().unwrap_or(""), options))
+            .collect();
+
+        let column = match column_type {
+            ColumnType::Integer => DataColumnEnum::IntColumn(DataColumn::new(
+                Self::parse_values(&normalized, lines, index, column_type)?,
+                name,
+            )),
+            ColumnType::Float => DataColumnEnum::FloatColumn(DataColumn::new(
+                Self::parse_values(&normalized, lines, index, column_type)?,
+                name,
+            )),
+            ColumnType::Boolean => DataColumnEnum::BoolColumn(DataColumn::new(
+                Self::parse_values(&normalized, lines, index, column_type)?,
+                name,
+            )),
+            ColumnType::Text => DataColumnEnum::TextColumn(DataColumn::new(values, name)),
+        };
+        Ok(column)
+    }
 
-                    let new_column = DataColumn::new(data_vec, column_names[index].to_owned());
-                    dataframe_columns.push(DataColumnEnum::TextColumn(new_column));
+    /// Parse the values of a column, where empty values are `None`
+    fn parse_values<T: FromStr>(
+        values: &[String],
+        lines: &[usize],
+        index: usize,
+        column_type: ColumnType,
+    ) -> Result<Vec<Option<T>>, DataframeError> {
+        values
+            .iter()
+            .zip(lines)
+            .map(|(value, &line)| {
+                if value.is_empty() {
+                    return Ok(None);
                 }
-            }
-        }
+                value
+                    .parse::<T>()
+                    .map(Some)
+                    .map_err(|_| DataframeError::Parse {
+                        row: line,
+                        col: index + 1,
+                        message: format!("'{value}' is not a valid {column_type} value"),
+                    })
+            })
+            .collect()
+    }
 
-        Ok(Dataframe {
-            columns: dataframe_columns,
-            rows_count: contents.lines().count() as u32,
-        })
+    /// Writes the `Dataframe` to a CSV file using a semicolon as the delimiter, so it can be read with `from_csv`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::dataframe::Dataframe;
+    ///
+    /// let path = String::from("./datasets/european_cities.csv");
+    /// let dataframe = Dataframe::from_csv(path).unwrap();
+    ///
+    /// let copy_path = std::env::temp_dir().join("european_cities_copy.csv");
+    /// dataframe.to_csv(copy_path.to_string_lossy().into_owned()).unwrap();
+    /// # std::fs::remove_file(copy_path).unwrap();
+    /// ```
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when the file could not be created or written
+    pub fn to_csv(&self, path: String) -> Result<(), DataframeError> {
+        self.to_file(path, ';')
     }
 
-    pub fn to_csv(&self, _path: String) -> Result<(), ()> {
-        unimplemented!()
+    /// Writes the `Dataframe` to a file using the given delimiter, with a header and empty fields for missing values
+    ///
+    /// Fields that contain the delimiter, quotes or line breaks are quoted.
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when the file could not be created or written
+    pub fn to_file(&self, path: String, delimiter: char) -> Result<(), DataframeError> {
+        self.to_file_with_options(path, &CsvWriteOptions::new().with_delimiter(delimiter))
+    }
+
+    /// Writes the `Dataframe` to a file using the given `CsvWriteOptions`
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when the file could not be created or written
+    pub fn to_file_with_options(
+        &self,
+        path: String,
+        options: &CsvWriteOptions,
+    ) -> Result<(), DataframeError> {
+        let file = File::create(path)?;
+        self.to_writer(BufWriter::new(file), options)
+    }
+
+    /// Writes the `Dataframe` as delimited text to any writer, using the given `CsvWriteOptions`
+    ///
+    /// Missing values are written as the `null_value`, and text values that are equal to it are quoted.
+    /// Reading the text with the `null_value` as a null token gives back the same missing and empty text values.
+    /// With `QuoteStyle::Never`, or a dialect without a quote character, the two can not be told apart.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::csv::CsvWriteOptions;
+    /// use rustic_ml::data_utils::dataframe::Dataframe;
+    ///
+    /// let path = String::from("./datasets/european_cities.csv");
+    /// let dataframe = Dataframe::from_csv(path).unwrap();
+    ///
+    /// // Tab separated values
+    /// let mut output: Vec<u8> = Vec::new();
+    /// dataframe.to_writer(&mut output, &CsvWriteOptions::new().with_delimiter('\t')).unwrap();
+    ///
+    /// let text = String::from_utf8(output).unwrap();
+    /// assert!(text.starts_with("Barcelona\tBelgrade\tBerlin"));
+    /// ```
+    ///
+    /// # Errors:
+    /// - `DataframeError::Io` when writing failed
+    pub fn to_writer<W: Write>(
+        &self,
+        writer: W,
+        options: &CsvWriteOptions,
+    ) -> Result<(), DataframeError> {
+        let mut csv_writer = CsvWriter::new(writer, options.dialect, options.quote_style);
+
+        if options.has_header {
+            csv_writer.write_record(self.column_names())?;
+        }
+
+        for row_index in 0..self.row_count() {
+            let values = self.columns.iter().map(|column| match column {
+                DataColumnEnum::IntColumn(c) => c.get(row_index).map(|v| v.to_string()),
+                DataColumnEnum::FloatColumn(c) => c.get(row_index).map(|v| v.to_string()),
+                DataColumnEnum::BoolColumn(c) => c.get(row_index).map(|v| v.to_string()),
+                DataColumnEnum::TextColumn(c) => c.get(row_index).cloned(),
+            });
+            csv_writer.write_record_with_nulls(values, &options.null_value)?;
+        }
+
+        csv_writer.flush()?;
+        Ok(())
     }
 
     /// Get all the column names for the `Dataframe`
@@ -351,29 +945,25 @@ impl Dataframe {
     /// assert!(dataframe.has_column("Barcelona"));
     /// assert!(!dataframe.has_column("Oslo"));
     ///
-    /// dataframe.rename_column(0, "Oslo");
+    /// dataframe.rename_column(0, "Oslo").unwrap();
     /// assert!(dataframe.has_column("Oslo"));
     /// ```
     ///
     /// # Errors
     ///
-    /// This method does not throw any error. If there is not column at given index, it does nothing.
-    /// Assume that given column is renamed, if a valid index is given.
-    pub fn rename_column(&mut self, index: usize, column_name: &str) {
-        if index < self.columns.len() {
-            match &mut self.columns[index] {
-                DataColumnEnum::IntColumn(data_column) => data_column.name = column_name.to_owned(),
-                DataColumnEnum::FloatColumn(data_column) => {
-                    data_column.name = column_name.to_owned()
-                }
-                DataColumnEnum::BoolColumn(data_column) => {
-                    data_column.name = column_name.to_owned()
-                }
-                DataColumnEnum::TextColumn(data_column) => {
-                    data_column.name = column_name.to_owned()
-                }
-            }
+    /// Returns `DataframeError::MissingColumn` if there is no column at the given index.
+    pub fn rename_column(&mut self, index: usize, column_name: &str) -> Result<(), DataframeError> {
+        let Some(column) = self.columns.get_mut(index) else {
+            return Err(DataframeError::MissingColumn(format!("at index {index}")));
+        };
+
+        match column {
+            DataColumnEnum::IntColumn(data_column) => data_column.name = column_name.to_owned(),
+            DataColumnEnum::FloatColumn(data_column) => data_column.name = column_name.to_owned(),
+            DataColumnEnum::BoolColumn(data_column) => data_column.name = column_name.to_owned(),
+            DataColumnEnum::TextColumn(data_column) => data_column.name = column_name.to_owned(),
         }
+        Ok(())
     }
 
     /// Print the first 5 rows of the `Dataframe`.
@@ -673,6 +1263,26 @@ impl Dataframe {
         total_memory
     }
 
+    /// Get the amount of rows in the `Dataframe`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustic_ml::data_utils::dataframe::Dataframe;
+    ///
+    /// let path = String::from("./datasets/european_cities.csv");
+    /// let dataframe = Dataframe::from_csv(path).unwrap();
+    ///
+    /// assert_eq!(dataframe.row_count(), 24);
+    /// ```
+    pub fn row_count(&self) -> usize {
+        if self.has_columns() {
+            self.rows_count as usize
+        } else {
+            0
+        }
+    }
+
     /// Check if the `Dataframe` has rows.
     ///
     /// # Example
@@ -810,7 +1420,7 @@ impl Dataframe {
 
     /// Drop the column with the given column name
     ///
-    /// Method is not verbose, and therefor assume that the column was removed, or that it never existed.
+    /// Returns `DataframeError::MissingColumn` if there is no column with the given name.
     ///
     /// # Example
     /// ```rust
@@ -821,17 +1431,23 @@ impl Dataframe {
     ///
     /// assert!(dataframe.has_column("Barcelona"));
     ///
-    /// dataframe.drop_column("Barcelona");
+    /// dataframe.drop_column("Barcelona").unwrap();
     /// assert!(!dataframe.has_column("Barcelona"));
+    /// assert!(dataframe.drop_column("Barcelona").is_err());
     /// ```
     ///
-    pub fn drop_column(&mut self, column_name: &str) {
+    pub fn drop_column(&mut self, column_name: &str) -> Result<(), DataframeError> {
+        if !self.has_column(column_name) {
+            return Err(DataframeError::MissingColumn(column_name.to_owned()));
+        }
+
         self.columns.retain(|col| match col {
             DataColumnEnum::IntColumn(int_col) => int_col.name != column_name,
             DataColumnEnum::FloatColumn(float_col) => float_col.name != column_name,
             DataColumnEnum::BoolColumn(bool_col) => bool_col.name != column_name,
             DataColumnEnum::TextColumn(text_col) => text_col.name != column_name,
-        })
+        });
+        Ok(())
     }
 
     /// Add a new column to the `Dataframe`
@@ -844,9 +1460,26 @@ impl Dataframe {
     /// let path = String::from("./datasets/european_cities.csv");
     /// let mut dataframe = Dataframe::from_csv(path).unwrap();
     ///
-    /// dataframe.add_column(vec![1, 2, 3, 4], "custom_index_column");
+    /// dataframe.add_column((0..24).collect(), "custom_index_column").unwrap();
+    /// assert!(dataframe.add_column(vec![1, 2, 3, 4], "too_short").is_err());
     /// ```
-    pub fn add_column<T: ToString>(&mut self, list: Vec<T>, column_name: &str) {
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataframeError::LengthMismatch` if the `Dataframe` has columns with another length than the list.
+    pub fn add_column<T: ToString>(
+        &mut self,
+        list: Vec<T>,
+        column_name: &str,
+    ) -> Result<(), DataframeError> {
+        if self.has_columns() && list.len() != self.rows_count as usize {
+            return Err(DataframeError::LengthMismatch {
+                expected: self.rows_count as usize,
+                found: list.len(),
+            });
+        }
+        self.rows_count = list.len() as u32;
+
         // Infer the column type based on the list values
         match Self::infer_column_type_from_vec(&list) {
             ColumnType::Integer => {
@@ -898,6 +1531,7 @@ impl Dataframe {
                 self.columns.push(DataColumnEnum::TextColumn(new_column));
             }
         };
+        Ok(())
     }
 
     pub fn add_record(&self) {
@@ -916,69 +1550,73 @@ impl Dataframe {
     /// assert!(dataframe.has_column("Barcelona"));
     /// assert!(!dataframe.has_column("Oslo"));
     ///
-    /// assert!(dataframe.get_column_type("Barcelona") == Some(ColumnType::Float));
-    /// assert!(dataframe.get_column_type("Oslo") == None);        
+    /// assert!(dataframe.get_column_type("Barcelona").unwrap() == ColumnType::Float);
+    /// assert!(dataframe.get_column_type("Oslo").is_err());
     /// ```
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `Ǹone` if no column had given name, or the `ColumnType` of the column with the given name.
+    /// - `DataframeError::MissingColumn` if there is no column with the given name
     ///
-    pub fn get_column_type(&self, column_name: &str) -> Option<ColumnType> {
+    /// # Returns
+    ///
+    /// Returns the `ColumnType` of the column with the given name.
+    pub fn get_column_type(&self, column_name: &str) -> Result<ColumnType, DataframeError> {
         // Iterate through each column and check if there is any column with the given name
         for column in &self.columns {
             match column {
                 DataColumnEnum::IntColumn(data_column) => {
                     if data_column.name == column_name {
-                        return Some(ColumnType::Integer);
+                        return Ok(ColumnType::Integer);
                     }
                 }
                 DataColumnEnum::FloatColumn(data_column) => {
                     if data_column.name == column_name {
-                        return Some(ColumnType::Float);
+                        return Ok(ColumnType::Float);
                     }
                 }
                 DataColumnEnum::BoolColumn(data_column) => {
                     if data_column.name == column_name {
-                        return Some(ColumnType::Boolean);
+                        return Ok(ColumnType::Boolean);
                     }
                 }
                 DataColumnEnum::TextColumn(data_column) => {
                     if data_column.name == column_name {
-                        return Some(ColumnType::Text);
+                        return Ok(ColumnType::Text);
                     }
                 }
             }
         }
 
-        // No column name match, return None
-        None
+        // No column name match
+        Err(DataframeError::MissingColumn(column_name.to_owned()))
     }
 
     /// Extract a single feature of floats into a `Vec<Option<f32>>`
     ///
     /// Creates a clone of the column. Values within the vector might be None.
     /// Use the column name to identify the column that will be extracted.
-    pub fn float_feature(&self, column_name: &str) -> Option<Vec<Option<f32>>> {
-        // Return none if there is no vector with
-        if !self.has_column(column_name) {
-            return None;
-        }
-
+    ///
+    /// # Errors
+    ///
+    /// - `DataframeError::MissingColumn` if there is no column with the given name
+    /// - `DataframeError::TypeMismatch` if the column is not a float column
+    pub fn float_feature(&self, column_name: &str) -> Result<Vec<Option<f32>>, DataframeError> {
         // Iterate through the columns until the correct one is found
         for column in &self.columns {
-            match column {
-                DataColumnEnum::FloatColumn(float_col) => {
-                    if float_col.name == column_name {
-                        return Some(float_col.extract());
-                    }
+            if let DataColumnEnum::FloatColumn(float_col) = column {
+                if float_col.name == column_name {
+                    return Ok(float_col.extract());
                 }
-                _ => continue,
             }
         }
 
-        // The desired column was not a float value
-        None
+        // The desired column did not exist, or was not a float column
+        Err(DataframeError::TypeMismatch {
+            column: column_name.to_owned(),
+            expected: ColumnType::Float,
+            found: self.get_column_type(column_name)?,
+        })
     }
 
     /// Extract two sets of features into a single vector of tuples (`Vec<Option<(f32, f32)>>`).
@@ -987,68 +1625,42 @@ impl Dataframe {
     /// A row in the vector is `None`, if one of the vectors are none.
     /// Use the column name to identify the column that will be extracted.
     ///
+    /// # Errors
+    ///
+    /// - `DataframeError::MissingColumn` if there is no column with one of the names
+    /// - `DataframeError::TypeMismatch` if one of the columns is not a float column
+    /// - `DataframeError::LengthMismatch` if the two columns are not the same length
     ///
     /// # Returns
     ///
     /// Returns a `Vec<Option<(f32, f32)>>` created from the two features.
-    /// Returns `None` if the two feature vectors are not the same length or of any vector did not exist.
     pub fn float_features(
         &self,
         first_column_name: &str,
         second_column_name: &str,
-    ) -> Option<Vec<Option<(f32, f32)>>> {
-        if !self.has_column(first_column_name) || !self.has_column(second_column_name) {
-            return None;
-        }
-
-        let mut first_column: Option<Vec<Option<f32>>> = None;
-        let mut second_column: Option<Vec<Option<f32>>> = None;
-
-        for column in &self.columns {
-            match column {
-                DataColumnEnum::FloatColumn(float_col) => {
-                    if float_col.name == first_column_name {
-                        first_column = Some(float_col.extract());
-                    } else if float_col.name == second_column_name {
-                        second_column = Some(float_col.extract());
-                    }
-                }
-                _ => continue,
-            }
-        }
-
-        // Return none if one of the columns are none
-        if first_column.is_none() || second_column.is_none() {
-            return None;
+    ) -> Result<Vec<Option<(f32, f32)>>, DataframeError> {
+        let first_vec = self.float_feature(first_column_name)?;
+        let second_vec = self.float_feature(second_column_name)?;
+
+        // Ensure the lengths of both vectors are the same
+        if first_vec.len() != second_vec.len() {
+            return Err(DataframeError::LengthMismatch {
+                expected: first_vec.len(),
+                found: second_vec.len(),
+            });
         }
 
-        // Merge the two columns
-        let merged_column: Option<Vec<Option<(f32, f32)>>> = match (first_column, second_column) {
-            (Some(first_vec), Some(second_vec)) => {
-                // Ensure the lengths of both vectors are the same
-                if first_vec.len() == second_vec.len() {
-                    // Combine the two vectors element-wise
-                    Some(
-                        first_vec
-                            .into_iter()
-                            .zip(second_vec.into_iter())
-                            .map(|(first_opt, second_opt)| {
-                                match (first_opt, second_opt) {
-                                    (Some(first_val), Some(second_val)) => {
-                                        Some((first_val, second_val))
-                                    }
-                                    _ => None, // If either is None, return None
-                                }
-                            })
-                            .collect(),
-                    )
-                } else {
-                    None
+        // Combine the two vectors element-wise
+        Ok(first_vec
+            .into_iter()
+            .zip(second_vec)
+            .map(|(first_opt, second_opt)| {
+                match (first_opt, second_opt) {
+                    (Some(first_val), Some(second_val)) => Some((first_val, second_val)),
+                    _ => None, // If either is None, return None
                 }
-            }
-            _ => None,
-        };
-        merged_column
+            })
+            .collect())
     }
 
     /// Get the value at given column and given row index.
@@ -1061,46 +1673,52 @@ impl Dataframe {
     /// let path = String::from("./datasets/european_cities.csv");
     /// let dataframe = Dataframe::from_csv(path).unwrap();
     ///
-    /// assert!(dataframe.at_str("Barcelona", 2) == Some("1497.61".to_string()));
+    /// assert!(dataframe.at_str("Barcelona", 2).unwrap() == Some("1497.61".to_string()));
+    /// assert!(dataframe.at_str("Barcelona", 50).unwrap().is_none());
+    /// assert!(dataframe.at_str("Oslo", 2).is_err());
     /// ```
     ///
+    /// # Errors
+    ///
+    /// - `DataframeError::MissingColumn` if there is no column with the given name
     ///
     /// # Returns
     ///
     /// The value as a `String` or `None` if:
-    /// - there was no column with that name
     /// - the given row index was out of bounce
     /// - the value at that entry was None
-    pub fn at_str(&self, column_name: &str, row_index: usize) -> Option<String> {
-        if self.has_column(column_name) {
-            for column in &self.columns {
-                match column {
-                    DataColumnEnum::IntColumn(data_column) => {
-                        if data_column.name == column_name {
-                            return data_column.get(row_index).map(|v| v.to_string());
-                        }
+    pub fn at_str(
+        &self,
+        column_name: &str,
+        row_index: usize,
+    ) -> Result<Option<String>, DataframeError> {
+        for column in &self.columns {
+            match column {
+                DataColumnEnum::IntColumn(data_column) => {
+                    if data_column.name == column_name {
+                        return Ok(data_column.get(row_index).map(|v| v.to_string()));
                     }
-                    DataColumnEnum::FloatColumn(data_column) => {
-                        if data_column.name == column_name {
-                            return data_column.get(row_index).map(|v| v.to_string());
-                        }
+                }
+                DataColumnEnum::FloatColumn(data_column) => {
+                    if data_column.name == column_name {
+                        return Ok(data_column.get(row_index).map(|v| v.to_string()));
                     }
-                    DataColumnEnum::BoolColumn(data_column) => {
-                        if data_column.name == column_name {
-                            return data_column.get(row_index).map(|v| v.to_string());
-                        }
+                }
+                DataColumnEnum::BoolColumn(data_column) => {
+                    if data_column.name == column_name {
+                        return Ok(data_column.get(row_index).map(|v| v.to_string()));
                     }
-                    DataColumnEnum::TextColumn(data_column) => {
-                        if data_column.name == column_name {
-                            return data_column.get(row_index).map(|v| v.to_string());
-                        }
+                }
+                DataColumnEnum::TextColumn(data_column) => {
+                    if data_column.name == column_name {
+                        return Ok(data_column.get(row_index).map(|v| v.to_string()));
                     }
                 }
             }
         }
 
-        // No match none is returned
-        None
+        // No column name match
+        Err(DataframeError::MissingColumn(column_name.to_owned()))
     }
 
     /// Get the value at given the index of the item.
@@ -1114,43 +1732,118 @@ impl Dataframe {
     /// let path = String::from("./datasets/european_cities.csv");
     /// let dataframe = Dataframe::from_csv(path).unwrap();
     ///
-    /// assert!(dataframe.at_index_str(2) == Some("1497.61".to_string()));
+    /// assert!(dataframe.at_index_str(2).unwrap() == Some("1497.61".to_string()));
+    /// assert!(dataframe.at_index_str(24 * 24).unwrap().is_none());
     /// ```
     ///
+    /// # Errors
+    ///
+    /// - `DataframeError::MissingColumn` if the `Dataframe` has no columns
     ///
     /// # Returns
     ///
     /// The value as a `String` or `None` if:
     /// - the given index was out of bounce
     /// - the value at that entry was None
-    pub fn at_index_str(&self, index: usize) -> Option<String> {
+    pub fn at_index_str(&self, index: usize) -> Result<Option<String>, DataframeError> {
+        if self.columns.is_empty() {
+            return Err(DataframeError::MissingColumn(format!("at index {index}")));
+        }
         let column_index = index % self.columns.len();
         let row_index = index / self.columns.len();
 
-        match &self.columns[column_index] {
+        // Out of bounds rows are `None`, in the same way as a missing value
+        let value = match &self.columns[column_index] {
             DataColumnEnum::IntColumn(data_column) => {
-                if row_index > data_column.size() {
-                    return None;
-                }
-                return data_column.get(row_index).map(|v| v.to_string());
+                data_column.get(row_index).map(|v| v.to_string())
             }
             DataColumnEnum::FloatColumn(data_column) => {
-                if row_index > data_column.size() {
-                    return None;
-                }
-                return data_column.get(row_index).map(|v| v.to_string());
+                data_column.get(row_index).map(|v| v.to_string())
             }
             DataColumnEnum::BoolColumn(data_column) => {
-                if row_index > data_column.size() {
-                    return None;
-                }
-                return data_column.get(row_index).map(|v| v.to_string());
+                data_column.get(row_index).map(|v| v.to_string())
             }
             DataColumnEnum::TextColumn(data_column) => {
-                if row_index > data_column.size() {
-                    return None;
-                }
-                return data_column.get(row_index).map(|v| v.to_string());
+                data_column.get(row_index).map(|v| v.to_string())
+            }
+        };
+        Ok(value)
+    }
+}
+
+/// Iterator over the rows of a CSV file as `Dataframe` chunks, created by `Dataframe::chunks_from_reader`
+///
+/// Every chunk has the same columns and column types, and `chunk_size` rows, except for the last chunk.
+pub struct DataframeChunks<R> {
+    reader: CsvReader<R>,
+    options: CsvReadOptions,
+    column_names: Vec<String>,
+    column_types: Vec<ColumnType>,
+    chunk_size: usize,
+    sample: IntoIter<CsvRecord>,
+    rows_left: usize,
+    done: bool,
+}
+
+impl<R: BufRead> DataframeChunks<R> {
+    /// The names of the columns in every chunk
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// The types of the columns in every chunk
+    pub fn column_types(&self) -> &[ColumnType] {
+        &self.column_types
+    }
+
+    /// Read the records of the next chunk, starting with the records that were read to infer the column types
+    fn next_records(&mut self) -> Result<Vec<CsvRecord>, DataframeError> {
+        let mut records: Vec<CsvRecord> = self.sample.by_ref().take(self.chunk_size).collect();
+
+        let count = (self.chunk_size - records.len()).min(self.rows_left);
+        let mut rest = Dataframe::read_records(&mut self.reader, count)?;
+        self.rows_left -= rest.len();
+
+        records.append(&mut rest);
+        Ok(records)
+    }
+}
+
+impl<R: BufRead> Iterator for DataframeChunks<R> {
+    type Item = Result<Dataframe, DataframeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let chunk = self.next_records().and_then(|records| {
+            if records.is_empty() {
+                return Ok(None);
+            }
+            let rows_count = records.len();
+            let columns = Dataframe::columns_from_records(
+                self.column_names.clone(),
+                &self.column_types,
+                records,
+                &self.options,
+            )?;
+            Ok(Some(Dataframe {
+                columns,
+                rows_count: rows_count as u32,
+            }))
+        });
+
+        // Stop after the last chunk, or the first error
+        match chunk {
+            Ok(Some(dataframe)) => Some(Ok(dataframe)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
             }
         }
     }
@@ -0,0 +1,1086 @@
+use crate::data_utils::float::Float;
+use crate::data_utils::matrix::{Matrix, MatrixError};
+
+/// LU decomposition of a square `Matrix`, with partial pivoting
+///
+/// Factors the matrix `A` into `PA = LU`, where `P` is a permutation matrix,
+/// `L` is a lower triangular matrix with ones on the diagonal and `U` is an upper triangular matrix.
+/// Both `L` and `U` are stored in a single matrix, the ones on the diagonal of `L` are implicit.
+/// Read more about LU decomposition: <https://en.wikipedia.org/wiki/LU_decomposition>
+pub struct LuDecomposition<T = f32> {
+    lu: Matrix<T>,
+    permutation: Vec<usize>,
+    sign: T,
+    singular: bool,
+}
+
+impl<T: Float> LuDecomposition<T> {
+    /// Factorize the given matrix
+    ///
+    /// For each column, the row with the largest absolute value is swapped up and used as pivot.
+    /// A singular matrix can still be factorized, but it can not be used to solve or invert.
+    /// Returns `MatrixError::NotSquare` if the matrix is not square.
+    pub fn new(matrix: &Matrix<T>) -> Result<Self, MatrixError> {
+        if matrix.rows != matrix.cols {
+            return Err(MatrixError::NotSquare(matrix.shape()));
+        }
+
+        let order = matrix.rows;
+        let mut lu = Matrix {
+            data: matrix.data.clone(),
+            rows: order,
+            cols: order,
+        };
+        let mut permutation: Vec<usize> = (0..order).collect();
+        let mut sign = T::ONE;
+        let mut singular = false;
+
+        // Pivots smaller than the tolerance are treated as zero
+        let tolerance = singular_tolerance(matrix);
+
+        for k in 0..order {
+            // Find the row with the largest value in the current column
+            let mut pivot_row = k;
+            let mut pivot_value = lu.data[k * order + k].abs();
+            for row in (k + 1)..order {
+                let value = lu.data[row * order + k].abs();
+                if value > pivot_value {
+                    pivot_row = row;
+                    pivot_value = value;
+                }
+            }
+
+            if pivot_row != k {
+                for col in 0..order {
+                    lu.data.swap(k * order + col, pivot_row * order + col);
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            if pivot_value <= tolerance {
+                // Nothing to eliminate with, the column is already zero below the diagonal
+                singular = true;
+                continue;
+            }
+
+            // Eliminate the values below the pivot
+            let pivot = lu.data[k * order + k];
+            for row in (k + 1)..order {
+                let factor = lu.data[row * order + k] / pivot;
+                lu.data[row * order + k] = factor;
+                for col in (k + 1)..order {
+                    let product = factor * lu.data[k * order + col];
+                    lu.data[row * order + col] -= product;
+                }
+            }
+        }
+
+        Ok(LuDecomposition {
+            lu,
+            permutation,
+            sign,
+            singular,
+        })
+    }
+
+    /// Get the lower triangular factor `L`
+    ///
+    /// The diagonal of `L` is always ones.
+    pub fn l(&self) -> Matrix<T> {
+        let order = self.lu.rows;
+        let mut l = Matrix::identity_typed(order);
+
+        for row in 0..order {
+            for col in 0..row {
+                l.data[row * order + col] = self.lu.data[row * order + col];
+            }
+        }
+
+        l
+    }
+
+    /// Get the upper triangular factor `U`
+    pub fn u(&self) -> Matrix<T> {
+        let order = self.lu.rows;
+        let mut u = Matrix::new_typed(order, order);
+
+        for row in 0..order {
+            for col in row..order {
+                u.data[row * order + col] = self.lu.data[row * order + col];
+            }
+        }
+
+        u
+    }
+
+    /// Get the permutation matrix `P`
+    ///
+    /// Multiplying `P` with the original matrix gives the same matrix as multiplying `L` and `U`.
+    pub fn p(&self) -> Matrix<T> {
+        let order = self.lu.rows;
+        let mut p = Matrix::new_typed(order, order);
+
+        for (row, original_row) in self.permutation.iter().enumerate() {
+            p.data[row * order + original_row] = T::ONE;
+        }
+
+        p
+    }
+
+    /// Get the row permutation as a list of indexes
+    ///
+    /// Row `i` of `PA` is row `permutation[i]` of the original matrix.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Check if the factorized matrix is singular
+    ///
+    /// A singular matrix has no inverse, and a linear system with it has no unique solution.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Calculate the determinant of the factorized matrix
+    ///
+    /// The determinant is the product of the diagonal of `U`, with the sign flipped for each row swap.
+    pub fn determinant(&self) -> T {
+        let order = self.lu.rows;
+        (0..order).fold(self.sign, |det, i| det * self.lu.data[i * order + i])
+    }
+
+    /// Solve the linear system `AX = B` for `X`
+    ///
+    /// Each column of `B` is a right-hand side, so several systems can be solved at once.
+    /// Returns `MatrixError::ShapeMismatch` if `B` does not have the same amount of rows as `A`,
+    /// and `MatrixError::SingularMatrix` if `A` is singular.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let order = self.lu.rows;
+        if b.rows != order {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.lu.shape(),
+                second_matrix_shape: b.shape(),
+            });
+        }
+
+        if self.singular {
+            return Err(MatrixError::SingularMatrix);
+        }
+
+        let rhs_count = b.cols;
+        let mut x = Matrix::new_typed(order, rhs_count);
+
+        // Apply the row permutation to B
+        for (row, original_row) in self.permutation.iter().enumerate() {
+            let start = original_row * rhs_count;
+            x.data[row * rhs_count..(row + 1) * rhs_count]
+                .copy_from_slice(&b.data[start..start + rhs_count]);
+        }
+
+        // Forward substitution with L
+        for row in 0..order {
+            for k in 0..row {
+                let factor = self.lu.data[row * order + k];
+                for col in 0..rhs_count {
+                    let product = factor * x.data[k * rhs_count + col];
+                    x.data[row * rhs_count + col] -= product;
+                }
+            }
+        }
+
+        // Backward substitution with U
+        for row in (0..order).rev() {
+            for k in (row + 1)..order {
+                let factor = self.lu.data[row * order + k];
+                for col in 0..rhs_count {
+                    let product = factor * x.data[k * rhs_count + col];
+                    x.data[row * rhs_count + col] -= product;
+                }
+            }
+
+            let pivot = self.lu.data[row * order + row];
+            for col in 0..rhs_count {
+                x.data[row * rhs_count + col] /= pivot;
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Calculate the inverse of the factorized matrix
+    ///
+    /// Solves the system `AX = I`.
+    /// Returns `MatrixError::SingularMatrix` if the matrix is singular.
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.solve(&Matrix::identity_typed(self.lu.rows))
+    }
+}
+
+/// Get the tolerance for when a pivot or diagonal value is treated as zero
+///
+/// Scaled by the size of the matrix and its largest absolute value.
+pub(crate) fn singular_tolerance<T: Float>(matrix: &Matrix<T>) -> T {
+    let max_value = matrix.data.iter().fold(T::ZERO, |max, v| max.max(v.abs()));
+    let size = T::from_usize(usize::max(matrix.rows, matrix.cols));
+
+    T::EPSILON * size * max_value
+}
+
+/// QR decomposition of a `Matrix`, computed with Householder reflections
+///
+/// Factors the `(mxn)` matrix `A` into `A = QR`, where `Q` has orthonormal columns and `R` is upper triangular.
+/// The Householder vectors are stored below the diagonal, and the diagonal of `R` is stored separately.
+/// Read more about QR decomposition: <https://en.wikipedia.org/wiki/QR_decomposition>
+pub struct QrDecomposition<T = f32> {
+    qr: Matrix<T>,
+    r_diagonal: Vec<T>,
+}
+
+impl<T: Float> QrDecomposition<T> {
+    /// Factorize the given matrix
+    ///
+    /// Each column below the diagonal is zeroed out by a Householder reflection.
+    /// Works for matrices of any shape.
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        let rows = matrix.rows;
+        let cols = matrix.cols;
+        let mut qr = Matrix {
+            data: matrix.data.clone(),
+            rows,
+            cols,
+        };
+        let steps = usize::min(rows, cols);
+        let mut r_diagonal: Vec<T> = Vec::with_capacity(steps);
+
+        for k in 0..steps {
+            // Norm of the column from the diagonal and down
+            let mut norm = (k..rows)
+                .map(|i| qr.data[i * cols + k] * qr.data[i * cols + k])
+                .sum::<T>()
+                .sqrt();
+
+            if norm != T::ZERO {
+                // Pick the sign that avoids cancellation
+                if qr.data[k * cols + k] < T::ZERO {
+                    norm = -norm;
+                }
+
+                // Create the Householder vector
+                for i in k..rows {
+                    qr.data[i * cols + k] /= norm;
+                }
+                qr.data[k * cols + k] += T::ONE;
+
+                // Apply the reflection to the remaining columns
+                for j in (k + 1)..cols {
+                    let s: T = (k..rows)
+                        .map(|i| qr.data[i * cols + k] * qr.data[i * cols + j])
+                        .sum();
+                    let s = -s / qr.data[k * cols + k];
+                    for i in k..rows {
+                        let product = s * qr.data[i * cols + k];
+                        qr.data[i * cols + j] += product;
+                    }
+                }
+            }
+
+            r_diagonal.push(-norm);
+        }
+
+        QrDecomposition { qr, r_diagonal }
+    }
+
+    /// Get the orthogonal factor `Q`
+    ///
+    /// For a `(mxn)` matrix the thin factor is returned, with shape `(mxk)` where `k = min(m, n)`.
+    pub fn q(&self) -> Matrix<T> {
+        let rows = self.qr.rows;
+        let cols = self.qr.cols;
+        let steps = self.r_diagonal.len();
+        let mut q = Matrix::new_typed(rows, steps);
+
+        // Apply the reflections to the columns of the identity, last reflection first
+        for k in (0..steps).rev() {
+            q.data[k * steps + k] = T::ONE;
+            for j in k..steps {
+                let pivot = self.qr.data[k * cols + k];
+                if pivot != T::ZERO {
+                    let s: T = (k..rows)
+                        .map(|i| self.qr.data[i * cols + k] * q.data[i * steps + j])
+                        .sum();
+                    let s = -s / pivot;
+                    for i in k..rows {
+                        q.data[i * steps + j] += s * self.qr.data[i * cols + k];
+                    }
+                }
+            }
+        }
+
+        q
+    }
+
+    /// Get the upper triangular factor `R`
+    ///
+    /// For a `(mxn)` matrix the thin factor is returned, with shape `(kxn)` where `k = min(m, n)`.
+    pub fn r(&self) -> Matrix<T> {
+        let cols = self.qr.cols;
+        let steps = self.r_diagonal.len();
+        let mut r = Matrix::new_typed(steps, cols);
+
+        for row in 0..steps {
+            r.data[row * cols + row] = self.r_diagonal[row];
+            for col in (row + 1)..cols {
+                r.data[row * cols + col] = self.qr.data[row * cols + col];
+            }
+        }
+
+        r
+    }
+
+    /// Estimate the rank of the factorized matrix
+    ///
+    /// Counts the values on the diagonal of `R` that are larger than a tolerance.
+    /// The tolerance is scaled by the size of the matrix and the largest value on the diagonal.
+    pub fn rank(&self) -> usize {
+        let max_value = self
+            .r_diagonal
+            .iter()
+            .fold(T::ZERO, |max, v| max.max(v.abs()));
+        let size = T::from_usize(usize::max(self.qr.rows, self.qr.cols));
+        let tolerance = T::EPSILON * size * max_value;
+
+        self.r_diagonal
+            .iter()
+            .filter(|v| v.abs() > tolerance)
+            .count()
+    }
+
+    /// Check if the factorized matrix has full column rank
+    ///
+    /// A `(mxn)` matrix has full column rank if its rank is `n`.
+    pub fn is_full_rank(&self) -> bool {
+        self.rank() == self.qr.cols
+    }
+
+    /// Solve the linear system `AX = B` in the least squares sense
+    ///
+    /// Finds the `X` that minimizes the norm of `AX - B`. Each column of `B` is a right-hand side.
+    /// For a square matrix with full rank, this is the exact solution.
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if `B` does not have the same amount of rows as `A`,
+    /// and `MatrixError::RankDeficient` if `A` does not have full column rank.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let rows = self.qr.rows;
+        let cols = self.qr.cols;
+        if b.rows != rows {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.qr.shape(),
+                second_matrix_shape: b.shape(),
+            });
+        }
+
+        let rank = self.rank();
+        if rank < cols {
+            return Err(MatrixError::RankDeficient {
+                rank,
+                required_rank: cols,
+            });
+        }
+
+        let rhs_count = b.cols;
+        let mut x = Matrix {
+            data: b.data.clone(),
+            rows,
+            cols: rhs_count,
+        };
+
+        // Compute Q^T * B by applying each reflection
+        for k in 0..cols {
+            let pivot = self.qr.data[k * cols + k];
+            for j in 0..rhs_count {
+                let s: T = (k..rows)
+                    .map(|i| self.qr.data[i * cols + k] * x.data[i * rhs_count + j])
+                    .sum();
+                let s = -s / pivot;
+                for i in k..rows {
+                    x.data[i * rhs_count + j] += s * self.qr.data[i * cols + k];
+                }
+            }
+        }
+
+        // Solve R * X = Q^T * B with backward substitution
+        for k in (0..cols).rev() {
+            for j in 0..rhs_count {
+                x.data[k * rhs_count + j] /= self.r_diagonal[k];
+            }
+            for i in 0..k {
+                let factor = self.qr.data[i * cols + k];
+                for j in 0..rhs_count {
+                    let product = x.data[k * rhs_count + j] * factor;
+                    x.data[i * rhs_count + j] -= product;
+                }
+            }
+        }
+
+        // Only the first n rows are part of the solution
+        x.data.truncate(cols * rhs_count);
+        x.rows = cols;
+
+        Ok(x)
+    }
+}
+
+/// Cholesky decomposition of a symmetric positive-definite `Matrix`
+///
+/// Factors the matrix `A` into `A = LL^T`, where `L` is a lower triangular matrix with positive diagonal.
+/// Solving with the Cholesky factor is about twice as fast as solving with LU decomposition.
+/// Read more about Cholesky decomposition: <https://en.wikipedia.org/wiki/Cholesky_decomposition>
+pub struct Cholesky<T = f32> {
+    l: Matrix<T>,
+}
+
+impl<T: Float> Cholesky<T> {
+    /// Factorize the given matrix
+    ///
+    /// Returns `MatrixError::NotSquare` if the matrix is not square, `MatrixError::NotSymmetric` if it is
+    /// not symmetric, and `MatrixError::NotPositiveDefinite` if it is not positive-definite.
+    pub fn new(matrix: &Matrix<T>) -> Result<Self, MatrixError> {
+        check_symmetric(matrix)?;
+
+        let order = matrix.rows;
+        let mut l = Matrix::new_typed(order, order);
+
+        for row in 0..order {
+            for col in 0..=row {
+                let sum: T = (0..col)
+                    .map(|k| l.data[row * order + k] * l.data[col * order + k])
+                    .sum();
+                let value = matrix.data[row * order + col] - sum;
+
+                if row == col {
+                    if value <= T::ZERO {
+                        return Err(MatrixError::NotPositiveDefinite);
+                    }
+                    l.data[row * order + col] = value.sqrt();
+                } else {
+                    l.data[row * order + col] = value / l.data[col * order + col];
+                }
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /// Get the lower triangular factor `L`
+    pub fn l(&self) -> Matrix<T> {
+        Matrix {
+            data: self.l.data.clone(),
+            rows: self.l.rows,
+            cols: self.l.cols,
+        }
+    }
+
+    /// Calculate the determinant of the factorized matrix
+    ///
+    /// The determinant is the squared product of the diagonal of `L`.
+    pub fn determinant(&self) -> T {
+        let order = self.l.rows;
+        let product: T = (0..order).map(|i| self.l.data[i * order + i]).product();
+        product * product
+    }
+
+    /// Calculate the natural logarithm of the determinant of the factorized matrix
+    ///
+    /// Avoids the overflow and underflow of `determinant` for large matrices.
+    /// Commonly used for the likelihood of a multivariate Gaussian distribution.
+    pub fn log_determinant(&self) -> T {
+        let order = self.l.rows;
+        T::from_f64(2.0)
+            * (0..order)
+                .map(|i| self.l.data[i * order + i].ln())
+                .sum::<T>()
+    }
+
+    /// Solve the linear system `AX = B` for `X`
+    ///
+    /// Solves `LY = B` with forward substitution and then `L^T X = Y` with backward substitution.
+    /// Each column of `B` is a right-hand side.
+    /// Returns `MatrixError::ShapeMismatch` if `B` does not have the same amount of rows as `A`.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let order = self.l.rows;
+        if b.rows != order {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.l.shape(),
+                second_matrix_shape: b.shape(),
+            });
+        }
+
+        let rhs_count = b.cols;
+        let mut x = Matrix {
+            data: b.data.clone(),
+            rows: order,
+            cols: rhs_count,
+        };
+
+        // Forward substitution with L
+        for row in 0..order {
+            for k in 0..row {
+                let factor = self.l.data[row * order + k];
+                for col in 0..rhs_count {
+                    let product = factor * x.data[k * rhs_count + col];
+                    x.data[row * rhs_count + col] -= product;
+                }
+            }
+            let pivot = self.l.data[row * order + row];
+            for col in 0..rhs_count {
+                x.data[row * rhs_count + col] /= pivot;
+            }
+        }
+
+        // Backward substitution with L^T
+        for row in (0..order).rev() {
+            for k in (row + 1)..order {
+                let factor = self.l.data[k * order + row];
+                for col in 0..rhs_count {
+                    let product = factor * x.data[k * rhs_count + col];
+                    x.data[row * rhs_count + col] -= product;
+                }
+            }
+            let pivot = self.l.data[row * order + row];
+            for col in 0..rhs_count {
+                x.data[row * rhs_count + col] /= pivot;
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Calculate the inverse of the factorized matrix
+    ///
+    /// Solves the system `AX = I`.
+    pub fn inverse(&self) -> Matrix<T> {
+        self.solve(&Matrix::identity_typed(self.l.rows))
+            .expect("Identity matrix has the same order as the factorized matrix")
+    }
+}
+
+/// Maximum amount of sweeps over the matrix before the Jacobi eigenvalue algorithm gives up
+const JACOBI_MAX_SWEEPS: usize = 100;
+
+/// Eigendecomposition of a symmetric `Matrix`
+///
+/// Factors the matrix `A` into `A = VΛV^T`, where `Λ` is a diagonal matrix of eigenvalues,
+/// and the columns of `V` are the orthonormal eigenvectors.
+/// Computed with the cyclic Jacobi eigenvalue algorithm.
+/// Eigenvalues are sorted from largest to smallest, which is the order used by PCA.
+/// Read more about the algorithm: <https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm>
+pub struct SymmetricEigen<T = f32> {
+    eigenvalues: Vec<T>,
+    eigenvectors: Matrix<T>,
+}
+
+impl<T: Float> SymmetricEigen<T> {
+    /// Decompose the given matrix
+    ///
+    /// Returns `MatrixError::NotSquare` if the matrix is not square, `MatrixError::NotSymmetric` if it is
+    /// not symmetric, and `MatrixError::NoConvergence` if the algorithm did not converge.
+    pub fn new(matrix: &Matrix<T>) -> Result<Self, MatrixError> {
+        check_symmetric(matrix)?;
+
+        let order = matrix.rows;
+        let mut a = Matrix {
+            data: matrix.data.clone(),
+            rows: order,
+            cols: order,
+        };
+        let mut v = Matrix::identity_typed(order);
+
+        let norm = matrix.data.iter().map(|x| *x * *x).sum::<T>().sqrt();
+        let tolerance = T::EPSILON * norm;
+
+        let mut converged = false;
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            // Norm of the values outside the diagonal
+            let mut off_diagonal = T::ZERO;
+            for p in 0..order {
+                for q in (p + 1)..order {
+                    off_diagonal +=
+                        T::from_f64(2.0) * a.data[p * order + q] * a.data[p * order + q];
+                }
+            }
+
+            if off_diagonal.sqrt() <= tolerance {
+                converged = true;
+                break;
+            }
+
+            for p in 0..order {
+                for q in (p + 1)..order {
+                    jacobi_rotate(&mut a, &mut v, p, q);
+                }
+            }
+        }
+
+        if !converged {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        // Sort the eigenvalues, and their eigenvectors, from largest to smallest
+        let mut order_indexes: Vec<usize> = (0..order).collect();
+        order_indexes.sort_by(|&i, &j| a.data[j * order + j].total_cmp(&a.data[i * order + i]));
+
+        let eigenvalues: Vec<T> = order_indexes
+            .iter()
+            .map(|&i| a.data[i * order + i])
+            .collect();
+        let mut eigenvectors = Matrix::new_typed(order, order);
+        for (new_col, &old_col) in order_indexes.iter().enumerate() {
+            for row in 0..order {
+                eigenvectors.data[row * order + new_col] = v.data[row * order + old_col];
+            }
+        }
+
+        Ok(SymmetricEigen {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+
+    /// Get the eigenvalues, sorted from largest to smallest
+    pub fn eigenvalues(&self) -> &[T] {
+        &self.eigenvalues
+    }
+
+    /// Get the eigenvectors as the columns of a matrix
+    ///
+    /// Column `i` is the eigenvector of the eigenvalue at index `i`.
+    pub fn eigenvectors(&self) -> Matrix<T> {
+        Matrix {
+            data: self.eigenvectors.data.clone(),
+            rows: self.eigenvectors.rows,
+            cols: self.eigenvectors.cols,
+        }
+    }
+
+    /// Get the eigenvalues as a diagonal matrix `Λ`
+    pub fn eigenvalue_matrix(&self) -> Matrix<T> {
+        let order = self.eigenvalues.len();
+        let mut lambda = Matrix::new_typed(order, order);
+
+        for (i, value) in self.eigenvalues.iter().enumerate() {
+            lambda.data[i * order + i] = *value;
+        }
+
+        lambda
+    }
+}
+
+/// Apply a single Jacobi rotation that zeroes out the value at `(p, q)` and `(q, p)`
+///
+/// Updates the matrix `a` to `J^T * a * J` and the accumulated rotations `v` to `v * J`.
+fn jacobi_rotate<T: Float>(a: &mut Matrix<T>, v: &mut Matrix<T>, p: usize, q: usize) {
+    let order = a.rows;
+    let apq = a.data[p * order + q];
+    if apq == T::ZERO {
+        return;
+    }
+
+    // Find the rotation angle, using the smaller root for stability
+    let theta = (a.data[q * order + q] - a.data[p * order + p]) / (T::from_f64(2.0) * apq);
+    let t = theta.signum() / (theta.abs() + (theta * theta + T::ONE).sqrt());
+    let c = T::ONE / (t * t + T::ONE).sqrt();
+    let s = t * c;
+
+    // Rotate the columns
+    for k in 0..order {
+        let akp = a.data[k * order + p];
+        let akq = a.data[k * order + q];
+        a.data[k * order + p] = c * akp - s * akq;
+        a.data[k * order + q] = s * akp + c * akq;
+    }
+
+    // Rotate the rows
+    for k in 0..order {
+        let apk = a.data[p * order + k];
+        let aqk = a.data[q * order + k];
+        a.data[p * order + k] = c * apk - s * aqk;
+        a.data[q * order + k] = s * apk + c * aqk;
+    }
+
+    // The rotation zeroes these values, set them exactly to avoid rounding noise
+    a.data[p * order + q] = T::ZERO;
+    a.data[q * order + p] = T::ZERO;
+
+    // Accumulate the rotation into the eigenvectors
+    for k in 0..order {
+        let vkp = v.data[k * order + p];
+        let vkq = v.data[k * order + q];
+        v.data[k * order + p] = c * vkp - s * vkq;
+        v.data[k * order + q] = s * vkp + c * vkq;
+    }
+}
+
+/// Check that the matrix is square and symmetric
+///
+/// Values are compared with a tolerance scaled by the size of the matrix and its largest absolute value.
+fn check_symmetric<T: Float>(matrix: &Matrix<T>) -> Result<(), MatrixError> {
+    if matrix.rows != matrix.cols {
+        return Err(MatrixError::NotSquare(matrix.shape()));
+    }
+
+    let order = matrix.rows;
+    let tolerance = singular_tolerance(matrix);
+    for row in 0..order {
+        for col in (row + 1)..order {
+            let difference = matrix.data[row * order + col] - matrix.data[col * order + row];
+            if difference.abs() > tolerance {
+                return Err(MatrixError::NotSymmetric);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Singular value decomposition of a `Matrix`
+///
+/// Factors the `(mxn)` matrix `A` into `A = UΣV^T`, where `U` and `V` have orthonormal columns,
+/// and `Σ` is a diagonal matrix of non-negative singular values, sorted from largest to smallest.
+/// The thin decomposition keeps `k = min(m, n)` columns in `U` and `V`, the full decomposition makes both square.
+/// Computed with the one-sided Jacobi algorithm.
+/// Read more about SVD: <https://en.wikipedia.org/wiki/Singular_value_decomposition>
+pub struct Svd<T = f32> {
+    u: Matrix<T>,
+    singular_values: Vec<T>,
+    v_t: Matrix<T>,
+}
+
+impl<T: Float> Svd<T> {
+    /// Compute the thin SVD of the given matrix
+    ///
+    /// `U` has shape `(mxk)`, `Σ` has shape `(kxk)` and `V^T` has shape `(kxn)`, where `k = min(m, n)`.
+    /// Returns `MatrixError::NoConvergence` if the algorithm did not converge.
+    pub fn new(matrix: &Matrix<T>) -> Result<Self, MatrixError> {
+        Self::compute(matrix, false)
+    }
+
+    /// Compute the full SVD of the given matrix
+    ///
+    /// `U` has shape `(mxm)`, `Σ` has shape `(mxn)` and `V^T` has shape `(nxn)`.
+    /// Returns `MatrixError::NoConvergence` if the algorithm did not converge.
+    pub fn full(matrix: &Matrix<T>) -> Result<Self, MatrixError> {
+        Self::compute(matrix, true)
+    }
+
+    fn compute(matrix: &Matrix<T>, full: bool) -> Result<Self, MatrixError> {
+        // One-sided Jacobi needs at least as many rows as columns, so wide matrices are transposed
+        let wide = matrix.rows < matrix.cols;
+        let tall = if wide {
+            matrix.get_transposed()
+        } else {
+            Matrix {
+                data: matrix.data.clone(),
+                rows: matrix.rows,
+                cols: matrix.cols,
+            }
+        };
+
+        let (mut left, singular_values, mut right) = one_sided_jacobi(&tall)?;
+
+        if full {
+            complete_orthonormal(&mut left, tall.rows, tall.rows);
+        }
+
+        // For a wide matrix A^T = UΣV^T, so A = VΣU^T
+        if wide {
+            std::mem::swap(&mut left, &mut right);
+        }
+
+        Ok(Svd {
+            u: from_columns(&left, matrix.rows),
+            singular_values,
+            v_t: from_columns(&right, matrix.cols).get_transposed(),
+        })
+    }
+
+    /// Get the left singular vectors `U` as the columns of a matrix
+    pub fn u(&self) -> Matrix<T> {
+        Matrix {
+            data: self.u.data.clone(),
+            rows: self.u.rows,
+            cols: self.u.cols,
+        }
+    }
+
+    /// Get the singular values, sorted from largest to smallest
+    pub fn singular_values(&self) -> &[T] {
+        &self.singular_values
+    }
+
+    /// Get the singular values as the diagonal matrix `Σ`
+    ///
+    /// The shape of `Σ` fits between `U` and `V^T`, so `U * Σ * V^T` is the decomposed matrix.
+    pub fn sigma(&self) -> Matrix<T> {
+        let mut sigma = Matrix::new_typed(self.u.cols, self.v_t.rows);
+
+        for (i, value) in self.singular_values.iter().enumerate() {
+            sigma.data[i * sigma.cols + i] = *value;
+        }
+
+        sigma
+    }
+
+    /// Get the right singular vectors `V^T` as the rows of a matrix
+    pub fn v_t(&self) -> Matrix<T> {
+        Matrix {
+            data: self.v_t.data.clone(),
+            rows: self.v_t.rows,
+            cols: self.v_t.cols,
+        }
+    }
+
+    /// Get the tolerance for when a singular value is treated as zero
+    ///
+    /// Uses the same default as NumPy, the machine epsilon scaled by the size of the matrix and the largest singular value.
+    pub fn tolerance(&self) -> T {
+        let largest = self.singular_values.first().copied().unwrap_or(T::ZERO);
+        let size = T::from_usize(usize::max(self.u.rows, self.v_t.cols));
+
+        T::EPSILON * size * largest
+    }
+
+    /// Get the rank of the decomposed matrix
+    ///
+    /// Counts the singular values that are larger than the tolerance.
+    pub fn rank(&self) -> usize {
+        let tolerance = self.tolerance();
+        self.singular_values
+            .iter()
+            .filter(|value| **value > tolerance)
+            .count()
+    }
+
+    /// Get the condition number of the decomposed matrix
+    ///
+    /// The ratio between the largest and smallest singular value.
+    /// Returns `T::INFINITY` if the smallest singular value is zero.
+    pub fn condition_number(&self) -> T {
+        let largest = self.singular_values.first().copied().unwrap_or(T::ZERO);
+        let smallest = self.singular_values.last().copied().unwrap_or(T::ZERO);
+
+        if smallest == T::ZERO {
+            return T::INFINITY;
+        }
+
+        largest / smallest
+    }
+
+    /// Calculate the Moore-Penrose pseudo-inverse of the decomposed matrix
+    ///
+    /// Computed as `VΣ^+U^T`, where `Σ^+` inverts the singular values above the tolerance.
+    /// The pseudo-inverse of a `(mxn)` matrix has shape `(nxm)`.
+    pub fn pseudo_inverse(&self) -> Matrix<T> {
+        let rows = self.v_t.cols;
+        let cols = self.u.rows;
+        let tolerance = self.tolerance();
+        let mut pinv = Matrix::new_typed(rows, cols);
+
+        for (k, value) in self.singular_values.iter().enumerate() {
+            if *value <= tolerance {
+                continue;
+            }
+
+            // Add the outer product of the k-th right and left singular vector
+            for row in 0..rows {
+                let v = self.v_t.data[k * self.v_t.cols + row] / *value;
+                for col in 0..cols {
+                    pinv.data[row * cols + col] += v * self.u.data[col * self.u.cols + k];
+                }
+            }
+        }
+
+        pinv
+    }
+
+    /// Get the best approximation of the decomposed matrix with the given rank
+    ///
+    /// Keeps only the `rank` largest singular values, see the Eckart-Young theorem.
+    /// Returns `MatrixError::IllegalRange` if `rank` is larger than the amount of singular values.
+    pub fn low_rank_approximation(&self, rank: usize) -> Result<Matrix<T>, MatrixError> {
+        if rank > self.singular_values.len() {
+            return Err(MatrixError::IllegalRange(format!(
+                "Rank {rank} is larger than the {} singular values",
+                self.singular_values.len()
+            )));
+        }
+
+        let rows = self.u.rows;
+        let cols = self.v_t.cols;
+        let mut approximation = Matrix::new_typed(rows, cols);
+
+        for (k, value) in self.singular_values.iter().take(rank).enumerate() {
+            for row in 0..rows {
+                let u = self.u.data[row * self.u.cols + k] * *value;
+                for col in 0..cols {
+                    approximation.data[row * cols + col] += u * self.v_t.data[k * cols + col];
+                }
+            }
+        }
+
+        Ok(approximation)
+    }
+}
+
+/// Maximum amount of sweeps over the column pairs before the one-sided Jacobi algorithm gives up
+const SVD_MAX_SWEEPS: usize = 60;
+
+/// Left singular vectors, singular values and right singular vectors, with the vectors stored as columns
+type JacobiSvd<T> = (Vec<Vec<T>>, Vec<T>, Vec<Vec<T>>);
+
+/// Thin SVD of a matrix with at least as many rows as columns
+///
+/// Rotates pairs of columns until all columns are orthogonal. The norms of the columns are then the singular values.
+/// Returns the left singular vectors, the singular values and the right singular vectors, sorted by singular value.
+fn one_sided_jacobi<T: Float>(matrix: &Matrix<T>) -> Result<JacobiSvd<T>, MatrixError> {
+    let rows = matrix.rows;
+    let cols = matrix.cols;
+
+    // Work on columns, since every rotation updates two full columns
+    let mut w: Vec<Vec<T>> = (0..cols)
+        .map(|col| (0..rows).map(|row| matrix.data[row * cols + col]).collect())
+        .collect();
+    let mut v: Vec<Vec<T>> = (0..cols)
+        .map(|col| {
+            (0..cols)
+                .map(|row| if row == col { T::ONE } else { T::ZERO })
+                .collect()
+        })
+        .collect();
+
+    let mut converged = false;
+    for _ in 0..SVD_MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..cols {
+            for q in (p + 1)..cols {
+                let alpha = dot(&w[p], &w[p]);
+                let beta = dot(&w[q], &w[q]);
+                let gamma = dot(&w[p], &w[q]);
+
+                if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // Find the rotation that makes the two columns orthogonal
+                let zeta = (beta - alpha) / (T::from_f64(2.0) * gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::ONE + zeta * zeta).sqrt());
+                let c = T::ONE / (T::ONE + t * t).sqrt();
+                let s = c * t;
+
+                rotate_columns(&mut w, p, q, c, s);
+                rotate_columns(&mut v, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+
+    if !converged {
+        return Err(MatrixError::NoConvergence);
+    }
+
+    // Sort by the column norms, from largest to smallest
+    let norms: Vec<T> = w.iter().map(|col| dot(col, col).sqrt()).collect();
+    let mut order: Vec<usize> = (0..cols).collect();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let singular_values: Vec<T> = order.iter().map(|&i| norms[i]).collect();
+    let right: Vec<Vec<T>> = order.iter().map(|&i| v[i].clone()).collect();
+
+    // Normalize the columns with a non-zero singular value, and complete the rest
+    let largest = singular_values.first().copied().unwrap_or(T::ZERO);
+    let tolerance = T::EPSILON * T::from_usize(rows) * largest;
+    let mut left: Vec<Vec<T>> = order
+        .iter()
+        .filter(|&&i| norms[i] > tolerance)
+        .map(|&i| w[i].iter().map(|x| *x / norms[i]).collect())
+        .collect();
+    complete_orthonormal(&mut left, rows, cols);
+
+    Ok((left, singular_values, right))
+}
+
+/// Rotate the two given columns with the rotation `(c, s)`
+fn rotate_columns<T: Float>(columns: &mut [Vec<T>], p: usize, q: usize, c: T, s: T) {
+    for i in 0..columns[p].len() {
+        let xp = columns[p][i];
+        let xq = columns[q][i];
+        columns[p][i] = c * xp - s * xq;
+        columns[q][i] = s * xp + c * xq;
+    }
+}
+
+/// Extend a set of orthonormal vectors with new orthonormal vectors, until there are `count` vectors
+///
+/// Each new vector is the standard basis vector with the largest component outside the current span,
+/// orthogonalized with Gram-Schmidt.
+fn complete_orthonormal<T: Float>(vectors: &mut Vec<Vec<T>>, dimension: usize, count: usize) {
+    while vectors.len() < count {
+        let mut best: Option<(T, Vec<T>)> = None;
+
+        for candidate in 0..dimension {
+            let mut vector: Vec<T> = vec![T::ZERO; dimension];
+            vector[candidate] = T::ONE;
+
+            // Orthogonalize twice for numerical stability
+            for _ in 0..2 {
+                for existing in vectors.iter() {
+                    let projection = dot(existing, &vector);
+                    for (x, e) in vector.iter_mut().zip(existing.iter()) {
+                        *x -= projection * *e;
+                    }
+                }
+            }
+
+            let norm = dot(&vector, &vector).sqrt();
+            let is_better = match &best {
+                Some((best_norm, _)) => norm > *best_norm,
+                None => true,
+            };
+            if is_better {
+                best = Some((norm, vector));
+            }
+        }
+
+        match best {
+            Some((norm, vector)) if norm > T::ZERO => {
+                vectors.push(vector.into_iter().map(|x| x / norm).collect());
+            }
+            _ => break,
+        }
+    }
+}
+
+/// Dot product of two vectors of the same length
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b.iter()).map(|(x, y)| *x * *y).sum()
+}
+
+/// Create a matrix with the given vectors as columns
+fn from_columns<T: Float>(columns: &[Vec<T>], rows: usize) -> Matrix<T> {
+    let cols = columns.len();
+    let mut matrix = Matrix::new_typed(rows, cols);
+
+    for (col, column) in columns.iter().enumerate() {
+        for (row, value) in column.iter().enumerate() {
+            matrix.data[row * cols + col] = *value;
+        }
+    }
+
+    matrix
+}
@@ -0,0 +1,174 @@
+use rand::distributions::uniform::SampleUniform;
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+/// Floating point number that can be stored in a `Matrix`
+///
+/// Implemented for `f32` and `f64`. Use `f32` for speed and memory, and `f64` when the extra
+/// precision is needed for numerical stability, for example when solving ill-conditioned systems.
+/// The methods have the same meaning as the methods with the same name on `f32` and `f64`.
+pub trait Float:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Display
+    + Default
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + Sum
+    + Product
+    + for<'a> Sum<&'a Self>
+    + SampleUniform
+{
+    /// The value `0`
+    const ZERO: Self;
+
+    /// The value `1`
+    const ONE: Self;
+
+    /// Difference between `1` and the next larger representable number
+    const EPSILON: Self;
+
+    /// Positive infinity
+    const INFINITY: Self;
+
+    /// Negative infinity
+    const NEG_INFINITY: Self;
+
+    /// Not a number
+    const NAN: Self;
+
+    /// Amount of bytes used to store a value
+    const BYTES: usize;
+
+    /// Convert from a `f64`, rounding to the nearest value if needed
+    fn from_f64(value: f64) -> Self;
+
+    /// Convert from a `usize`, rounding to the nearest value if needed
+    fn from_usize(value: usize) -> Self;
+
+    /// Convert to a `f64`
+    fn to_f64(self) -> f64;
+
+    /// Absolute value
+    fn abs(self) -> Self;
+
+    /// Square root, `NaN` for negative values
+    fn sqrt(self) -> Self;
+
+    /// Natural logarithm
+    fn ln(self) -> Self;
+
+    /// `1` for positive values, and `-1` for negative values
+    fn signum(self) -> Self;
+
+    /// The largest of the two values, ignoring `NaN`
+    fn max(self, other: Self) -> Self;
+
+    /// The smallest of the two values, ignoring `NaN`
+    fn min(self, other: Self) -> Self;
+
+    /// Check if the value is `NaN`
+    fn is_nan(self) -> bool;
+
+    /// Total ordering of the values, also for `NaN`, so a slice of values can be sorted
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Append the little-endian bytes of the value to the vector
+    fn extend_le_bytes(self, bytes: &mut Vec<u8>);
+
+    /// Create a value from its little-endian bytes
+    ///
+    /// Panics if the slice is not exactly `BYTES` long.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+}
+
+// Implement `Float` by forwarding to the methods of the primitive type
+macro_rules! impl_float {
+    ($float:ident) => {
+        impl Float for $float {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = $float::EPSILON;
+            const INFINITY: Self = $float::INFINITY;
+            const NEG_INFINITY: Self = $float::NEG_INFINITY;
+            const NAN: Self = $float::NAN;
+            const BYTES: usize = std::mem::size_of::<$float>();
+
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+
+            fn from_usize(value: usize) -> Self {
+                value as $float
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn abs(self) -> Self {
+                $float::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $float::sqrt(self)
+            }
+
+            fn ln(self) -> Self {
+                $float::ln(self)
+            }
+
+            fn signum(self) -> Self {
+                $float::signum(self)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $float::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $float::min(self, other)
+            }
+
+            fn is_nan(self) -> bool {
+                $float::is_nan(self)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $float::total_cmp(self, other)
+            }
+
+            fn extend_le_bytes(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let bytes = bytes
+                    .try_into()
+                    .expect("slice must have the size of the float type");
+                $float::from_le_bytes(bytes)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
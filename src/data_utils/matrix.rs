@@ -1,19 +1,56 @@
+use crate::data_utils::decomposition::{
+    Cholesky, LuDecomposition, QrDecomposition, Svd, SymmetricEigen,
+};
+use crate::data_utils::float::Float;
+use crate::data_utils::matrix_view::MatrixView;
 use rand::{
     distributions::{Standard, Uniform},
-    Rng,
+    rngs::StdRng,
+    Rng, SeedableRng,
 };
 use std::{
     fmt::Display,
-    ops::{Range, RangeInclusive},
+    iter::{Skip, StepBy},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Range,
+        RangeInclusive, Sub, SubAssign,
+    },
+    slice::{Iter, IterMut},
 };
 
+/// Size of the square tiles used when transposing a matrix.
+///
+/// A tile of `32x32` values of `f32` fits comfortably in the L1 cache.
+const TRANSPOSE_BLOCK_SIZE: usize = 32;
+
+/// Tolerance used when checking if a matrix is orthogonal
+const ORTHOGONAL_TOLERANCE: f64 = 1e-5;
+
+/// Size of the square tiles used when multiplying matrices.
+///
+/// A row of `64` values from both matrices and the `64x64` tile of the result stay in cache while a tile is computed.
+const MULTIPLY_BLOCK_SIZE: usize = 64;
+
+/// Amount of multiply-add operations before `Matrix::multiply` splits the work across threads.
+///
+/// Smaller multiplications are faster on a single thread, as starting threads has a cost.
+const MULTIPLY_PARALLEL_THRESHOLD: usize = 128 * 128 * 128;
+
+/// Amount of values in a matrix before `Display` and `Debug` only print the rows and columns at the edges.
+const DISPLAY_THRESHOLD: usize = 1000;
+
+/// Amount of rows and columns printed at each edge of a matrix that is too large to print in full.
+const DISPLAY_EDGE_ITEMS: usize = 3;
+
 /// Matrix implementation
 ///
 /// A mathematical data structure.
+/// The values are of type `T`, which is `f32` unless another `Float`, such as `f64`, is given.
 /// Read more about matrices here:
 /// <https://en.wikipedia.org/wiki/Matrix_(mathematics)>
-pub struct Matrix {
-    pub data: Vec<f32>,
+#[derive(Clone, PartialEq)]
+pub struct Matrix<T = f32> {
+    pub data: Vec<T>,
     pub rows: usize,
     pub cols: usize,
 }
@@ -39,6 +76,42 @@ pub enum MatrixError {
     /// Matrix multiplication with two given matrixes sizes (mxn) and (qxp)
     /// The columns (n) must equal rows (q) => n == q
     MatrixMultiply,
+
+    /// Operation requires a square matrix, but the matrix with the given shape was not square
+    NotSquare(String),
+
+    /// Operation requires an invertible matrix, but the matrix was singular
+    SingularMatrix,
+
+    /// Operation requires a matrix with full column rank
+    RankDeficient {
+        /// Estimated rank of the matrix.
+        rank: usize,
+
+        /// Rank that was required by the operation.
+        required_rank: usize,
+    },
+
+    /// Operation requires a symmetric matrix
+    NotSymmetric,
+
+    /// Operation requires a positive-definite matrix
+    NotPositiveDefinite,
+
+    /// Iterative algorithm did not converge within its maximum amount of iterations
+    NoConvergence,
+
+    /// Random distribution was given parameters it is not defined for
+    InvalidDistribution(String),
+
+    /// Operation requires a tensor with a specific amount of dimensions
+    DimensionMismatch {
+        /// Amount of dimensions that was required by the operation.
+        expected: usize,
+
+        /// Amount of dimensions of the given tensor.
+        found: usize,
+    },
 }
 
 // For printing the error of the matrix
@@ -60,6 +133,39 @@ impl Display for MatrixError {
             MatrixError::MatrixMultiply => {
                 write!(f, "Illegal to multiply the two given matrixes.")
             }
+            MatrixError::NotSquare(shape) => {
+                write!(f, "The matrix with shape ({shape}) is not a square matrix")
+            }
+            MatrixError::SingularMatrix => {
+                write!(f, "The matrix is singular and can not be inverted")
+            }
+            MatrixError::RankDeficient {
+                rank,
+                required_rank,
+            } => {
+                write!(
+                    f,
+                    "The matrix has rank {rank}, but rank {required_rank} is required"
+                )
+            }
+            MatrixError::NotSymmetric => {
+                write!(f, "The matrix is not symmetric")
+            }
+            MatrixError::NotPositiveDefinite => {
+                write!(f, "The matrix is not positive-definite")
+            }
+            MatrixError::NoConvergence => {
+                write!(f, "The matrix algorithm did not converge")
+            }
+            MatrixError::InvalidDistribution(val) => {
+                write!(f, "Invalid distribution given: {val}")
+            }
+            MatrixError::DimensionMismatch { expected, found } => {
+                write!(
+                    f,
+                    "The tensor has {found} dimensions, but {expected} dimensions are required"
+                )
+            }
         }
     }
 }
@@ -67,6 +173,132 @@ impl Display for MatrixError {
 // Making the MatrixError an Error
 impl std::error::Error for MatrixError {}
 
+/// Multiply the rows of `lhs` with the transposed right hand matrix `rhs_transposed`, and add them to `result`
+///
+/// All slices are row-major, and `inner` is the amount of columns of `lhs` and `rhs_transposed`.
+/// The result is computed in tiles of `MULTIPLY_BLOCK_SIZE`, so the rows of a tile are reused from the cache.
+fn multiply_block<T: Float>(lhs: &[T], rhs_transposed: &[T], result: &mut [T], inner: usize) {
+    let rows = lhs.len() / inner;
+    let cols = rhs_transposed.len() / inner;
+
+    for row_block in (0..rows).step_by(MULTIPLY_BLOCK_SIZE) {
+        let row_end = (row_block + MULTIPLY_BLOCK_SIZE).min(rows);
+        for col_block in (0..cols).step_by(MULTIPLY_BLOCK_SIZE) {
+            let col_end = (col_block + MULTIPLY_BLOCK_SIZE).min(cols);
+            for inner_block in (0..inner).step_by(MULTIPLY_BLOCK_SIZE) {
+                let inner_end = (inner_block + MULTIPLY_BLOCK_SIZE).min(inner);
+                for row in row_block..row_end {
+                    let lhs_row = &lhs[row * inner + inner_block..row * inner + inner_end];
+                    for col in col_block..col_end {
+                        let rhs_row =
+                            &rhs_transposed[col * inner + inner_block..col * inner + inner_end];
+                        result[row * cols + col] += dot_product(lhs_row, rhs_row);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Dot product of two slices of the same length
+///
+/// Sums into several accumulators, so the compiler can vectorize the loop.
+fn dot_product<T: Float>(a: &[T], b: &[T]) -> T {
+    let mut sums = [T::ZERO; 8];
+    let a_chunks = a.chunks_exact(8);
+    let b_chunks = b.chunks_exact(8);
+    let remainder: T = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| *x * *y)
+        .sum();
+
+    for (a_chunk, b_chunk) in a_chunks.zip(b_chunks) {
+        for ((sum, x), y) in sums.iter_mut().zip(a_chunk).zip(b_chunk) {
+            *sum += *x * *y;
+        }
+    }
+
+    sums.iter().sum::<T>() + remainder
+}
+
+/// Sample a value from the standard normal distribution, with mean `0` and standard deviation `1`
+///
+/// Uses the Box-Muller transform on two uniform values. The first uniform value is in `(0, 1]`, so the logarithm is finite.
+fn sample_standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Check that the parameters of a normal distribution are finite, and that the standard deviation is not negative
+fn check_normal(mean: f64, std_dev: f64) -> Result<(), MatrixError> {
+    if !mean.is_finite() || !std_dev.is_finite() || std_dev < 0.0 {
+        return Err(MatrixError::InvalidDistribution(format!(
+            "normal distribution with mean {mean} and standard deviation {std_dev}"
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the ranges describe a region with at least one value inside a `(rows x cols)` shape
+pub(crate) fn check_region(
+    row_range: &Range<usize>,
+    col_range: &Range<usize>,
+    rows: usize,
+    cols: usize,
+) -> Result<(), MatrixError> {
+    // Check if the ranges fit the shape
+    if row_range.end > rows || col_range.end > cols {
+        return Err(MatrixError::IllegalRange(format!(
+            "Did not match the shape: {rows}x{cols}"
+        )));
+    }
+
+    // Check that the range is sequential and length at least 1
+    if row_range.start >= row_range.end || col_range.start >= col_range.end {
+        return Err(MatrixError::IllegalRange(
+            "Range must start go from low to high value, and length at least 1".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Axis of a `Matrix` that an operation is applied along
+///
+/// Reducing along `Axis::Rows` collapses the rows, and gives one value per column as a row vector `(1xn)`.
+/// Reducing along `Axis::Cols` collapses the columns, and gives one value per row as a column vector `(mx1)`.
+/// This is the same as `axis=0` and `axis=1` in NumPy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Along the rows, one result for each column
+    Rows,
+
+    /// Along the columns, one result for each row
+    Cols,
+}
+
+/// Matrix norms that can be calculated with `Matrix::norm`
+///
+/// Read more about matrix norms: <https://en.wikipedia.org/wiki/Matrix_norm>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Norm {
+    /// Square root of the sum of all squared values
+    Frobenius,
+
+    /// Largest sum of absolute values in a column
+    L1,
+
+    /// Largest sum of absolute values in a row
+    Infinity,
+}
+
+// Constructors of `f32` matrices
+//
+// Kept apart from the generic constructors, so `Matrix::new(2, 3)` infers `Matrix<f32>` without annotations.
+// Use the `_typed` constructors to create a matrix of another `Float`, such as `Matrix::<f64>::new_typed(2, 3)`.
 impl Matrix {
     /// Create a new empty matrix
     ///
@@ -74,8 +306,7 @@ impl Matrix {
     /// All values within the matrix is 0.0.
     /// Returns an instance of Matrix.
     pub fn new(rows: usize, cols: usize) -> Self {
-        let data = vec![0.0; rows * cols];
-        Matrix { data, rows, cols }
+        Self::new_typed(rows, cols)
     }
 
     /// Create a identity matrix from the given order
@@ -83,10 +314,40 @@ impl Matrix {
     /// Creates an empty matrix from the given order. Sets the value 1.0 on the main diagonal of the matrix
     /// Read more about Identity Matrix: <https://en.wikipedia.org/wiki/Identity_matrix>
     pub fn identity(order: usize) -> Self {
-        let mut data = vec![0.0; order * order];
+        Self::identity_typed(order)
+    }
+
+    /// Create a new matrix based on given data
+    ///
+    /// Given the amount of rows and columns and the vector of data, it creates a new instance of the matrix.
+    /// Creates a matrix based on the given amount of columns. Will add any missing values as default value 0.0.
+    /// Makes sure that the Matrix has completed rows.
+    pub fn from_vec(cols: usize, data: Vec<f32>) -> Self {
+        Self::from_vec_typed(cols, data)
+    }
+
+    pub fn with_rand_range(rows: usize, cols: usize, value_range: RangeInclusive<f32>) -> Self {
+        Self::with_rand_range_typed(rows, cols, value_range)
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Create a new empty matrix of the given `Float` type
+    ///
+    /// Same as `Matrix::new`, for example `Matrix::<f64>::new_typed(2, 3)`.
+    pub fn new_typed(rows: usize, cols: usize) -> Self {
+        let data = vec![T::ZERO; rows * cols];
+        Matrix { data, rows, cols }
+    }
+
+    /// Create a identity matrix of the given `Float` type
+    ///
+    /// Same as `Matrix::identity`, for example `Matrix::<f64>::identity_typed(3)`.
+    pub fn identity_typed(order: usize) -> Self {
+        let mut data = vec![T::ZERO; order * order];
 
         for i in 0..order {
-            data[i * order + i] = 1.0;
+            data[i * order + i] = T::ONE;
         }
 
         Matrix {
@@ -96,17 +357,15 @@ impl Matrix {
         }
     }
 
-    /// Create a new matrix based on given data
+    /// Create a new matrix based on given data of any `Float` type
     ///
-    /// Given the amount of rows and columns and the vector of data, it creates a new instance of the matrix.
-    /// Creates a matrix based on the given amount of columns. Will add any missing values as default value 0.0.
-    /// Makes sure that the Matrix has completed rows.
-    pub fn from_vec(cols: usize, mut data: Vec<f32>) -> Self {
+    /// Same as `Matrix::from_vec`, the type is taken from the data, for example `Matrix::from_vec_typed(2, vec![1.0_f64; 4])`.
+    pub fn from_vec_typed(cols: usize, mut data: Vec<T>) -> Self {
         let missing_values = cols - (data.len() % cols);
 
         if missing_values < cols {
             for _ in 0..missing_values {
-                data.push(0.0);
+                data.push(T::ZERO);
             }
         }
 
@@ -114,36 +373,159 @@ impl Matrix {
         Matrix { rows, cols, data }
     }
 
-    pub fn with_rand_range(rows: usize, cols: usize, value_range: RangeInclusive<f32>) -> Self {
-        let mut rng = rand::thread_rng();
-        let data: Vec<f32> = (0..rows * cols)
+    /// Create a matrix with uniform random values from a range of any `Float` type
+    ///
+    /// Same as `Matrix::with_rand_range`, for example `Matrix::with_rand_range_typed(2, 2, -1.0_f64..=1.0)`.
+    pub fn with_rand_range_typed(rows: usize, cols: usize, value_range: RangeInclusive<T>) -> Self {
+        Self::with_rand_range_rng(rows, cols, value_range, &mut rand::thread_rng())
+    }
+
+    /// Create a matrix with uniform random values from the range, using the given random number generator
+    ///
+    /// Passing a seeded generator, such as `StdRng::seed_from_u64(42)`, gives the same matrix every time.
+    /// Panics if the range is empty, in the same way as `Rng::gen_range`.
+    pub fn with_rand_range_rng<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        value_range: RangeInclusive<T>,
+        rng: &mut R,
+    ) -> Self {
+        let data: Vec<T> = (0..rows * cols)
             .map(|_| rng.gen_range(value_range.clone()))
             .collect();
         Matrix { data, rows, cols }
     }
 
-    pub fn with_rand_bin(rows: usize, cols: usize) -> Self {
-        let data: Vec<f32> = rand::thread_rng()
-            .sample_iter(Standard)
-            .take(rows * cols)
+    /// Create a matrix with uniform random values from the range, generated from the given seed
+    ///
+    /// The same seed always gives the same matrix, so experiments and tests can be reproduced.
+    /// Panics if the range is empty, in the same way as `Rng::gen_range`.
+    pub fn with_rand_range_seeded(
+        rows: usize,
+        cols: usize,
+        value_range: RangeInclusive<T>,
+        seed: u64,
+    ) -> Self {
+        Self::with_rand_range_rng(rows, cols, value_range, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Create a matrix with values from a normal distribution
+    ///
+    /// The values are drawn from a normal distribution with the given mean and standard deviation.
+    /// Read more about the normal distribution: <https://en.wikipedia.org/wiki/Normal_distribution>
+    /// Returns `MatrixError::InvalidDistribution` if the parameters are not finite, or the standard deviation is negative.
+    pub fn with_rand_normal<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        mean: T,
+        std_dev: T,
+        rng: &mut R,
+    ) -> Result<Self, MatrixError> {
+        let (mean, std_dev) = (mean.to_f64(), std_dev.to_f64());
+        check_normal(mean, std_dev)?;
+
+        let data: Vec<T> = (0..rows * cols)
+            .map(|_| T::from_f64(mean + std_dev * sample_standard_normal(rng)))
             .collect();
-        Matrix { data, rows, cols }
+        Ok(Matrix { data, rows, cols })
     }
 
-    pub fn with_rand_0_to_10(rows: usize, cols: usize) -> Self {
-        let distribution: Uniform<f32> = Uniform::new_inclusive(0.0, 10.0);
-        let data: Vec<f32> = rand::thread_rng()
-            .sample_iter(distribution)
-            .take(rows * cols)
+    /// Create a matrix with values from a truncated normal distribution
+    ///
+    /// Same as `with_rand_normal`, but values more than two standard deviations from the mean are drawn again.
+    /// This avoids the large initial weights that a normal distribution sometimes gives.
+    /// Returns `MatrixError::InvalidDistribution` if the parameters are not finite, or the standard deviation is negative.
+    pub fn with_rand_truncated_normal<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        mean: T,
+        std_dev: T,
+        rng: &mut R,
+    ) -> Result<Self, MatrixError> {
+        let (mean, std_dev) = (mean.to_f64(), std_dev.to_f64());
+        check_normal(mean, std_dev)?;
+
+        let data: Vec<T> = (0..rows * cols)
+            .map(|_| loop {
+                let sample = sample_standard_normal(rng);
+                if sample.abs() <= 2.0 {
+                    break T::from_f64(mean + std_dev * sample);
+                }
+            })
+            .collect();
+        Ok(Matrix { data, rows, cols })
+    }
+
+    /// Create a matrix with values from a Bernoulli distribution
+    ///
+    /// Each value is 1.0 with the given probability, and 0.0 otherwise, for example to create a dropout mask.
+    /// Returns `MatrixError::InvalidDistribution` if the probability is not within `[0, 1]`.
+    pub fn with_rand_bernoulli<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        probability: f64,
+        rng: &mut R,
+    ) -> Result<Self, MatrixError> {
+        if !(0.0..=1.0).contains(&probability) {
+            return Err(MatrixError::InvalidDistribution(format!(
+                "bernoulli distribution with probability {probability}"
+            )));
+        }
+
+        let data: Vec<T> = (0..rows * cols)
+            .map(|_| {
+                if rng.gen_bool(probability) {
+                    T::ONE
+                } else {
+                    T::ZERO
+                }
+            })
+            .collect();
+        Ok(Matrix { data, rows, cols })
+    }
+
+    /// Create a weight matrix with the Xavier/Glorot uniform initialization
+    ///
+    /// The matrix is seen as the weights of a layer with `rows` inputs and `cols` outputs, so `fan_in = rows` and `fan_out = cols`.
+    /// Values are uniform within `[-limit, limit]`, where `limit = sqrt(6 / (fan_in + fan_out))`.
+    /// Suited for layers with `tanh` or sigmoid activations.
+    /// Read more: <https://proceedings.mlr.press/v9/glorot10a.html>
+    pub fn with_xavier_uniform<R: Rng + ?Sized>(rows: usize, cols: usize, rng: &mut R) -> Self {
+        let limit = T::from_f64((6.0 / (rows + cols).max(1) as f64).sqrt());
+        Self::with_rand_range_rng(rows, cols, -limit..=limit, rng)
+    }
+
+    /// Create a weight matrix with the Xavier/Glorot normal initialization
+    ///
+    /// Uses `fan_in = rows` and `fan_out = cols`, in the same way as `with_xavier_uniform`.
+    /// Values are drawn from a normal distribution with mean 0 and standard deviation `sqrt(2 / (fan_in + fan_out))`.
+    pub fn with_xavier_normal<R: Rng + ?Sized>(rows: usize, cols: usize, rng: &mut R) -> Self {
+        let std_dev = (2.0 / (rows + cols).max(1) as f64).sqrt();
+        let data: Vec<T> = (0..rows * cols)
+            .map(|_| T::from_f64(std_dev * sample_standard_normal(rng)))
             .collect();
         Matrix { data, rows, cols }
     }
 
-    pub fn with_rand_neg10_to_10(rows: usize, cols: usize) -> Self {
-        let distribution: Uniform<f32> = Uniform::new_inclusive(-10.0, 10.0);
-        let data: Vec<f32> = rand::thread_rng()
-            .sample_iter(distribution)
-            .take(rows * cols)
+    /// Create a weight matrix with the He uniform initialization
+    ///
+    /// Uses `fan_in = rows`, in the same way as `with_xavier_uniform`.
+    /// Values are uniform within `[-limit, limit]`, where `limit = sqrt(6 / fan_in)`.
+    /// Suited for layers with ReLU activations.
+    /// Read more: <https://arxiv.org/abs/1502.01852>
+    pub fn with_he_uniform<R: Rng + ?Sized>(rows: usize, cols: usize, rng: &mut R) -> Self {
+        let limit = T::from_f64((6.0 / rows.max(1) as f64).sqrt());
+        Self::with_rand_range_rng(rows, cols, -limit..=limit, rng)
+    }
+
+    /// Create a weight matrix with the He normal initialization
+    ///
+    /// Uses `fan_in = rows`, in the same way as `with_xavier_uniform`.
+    /// Values are drawn from a normal distribution with mean 0 and standard deviation `sqrt(2 / fan_in)`.
+    pub fn with_he_normal<R: Rng + ?Sized>(rows: usize, cols: usize, rng: &mut R) -> Self {
+        let std_dev = (2.0 / rows.max(1) as f64).sqrt();
+        let data: Vec<T> = (0..rows * cols)
+            .map(|_| T::from_f64(std_dev * sample_standard_normal(rng)))
             .collect();
         Matrix { data, rows, cols }
     }
@@ -152,7 +534,7 @@ impl Matrix {
     ///
     /// Given the row and column of the item, retrieve a reference to the item.
     /// If there is not item, or if the row anc column given was to high, it returns None.
-    pub fn get(&self, row: usize, col: usize) -> Option<&f32> {
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
         let index = row * self.cols + col;
         if row >= self.rows || col >= self.cols || index >= self.data.len() {
             return None;
@@ -164,7 +546,7 @@ impl Matrix {
     ///
     /// Given the row and column of the item, retrieve a mutable reference to the item.
     /// If there is not item, or if the row anc column given was to high, it returns None.
-    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut f32> {
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
         let index = row * self.cols + col;
         if row >= self.rows || col >= self.cols || index >= self.data.len() {
             return None;
@@ -176,7 +558,7 @@ impl Matrix {
     ///
     /// Takes the index of the row, and returns a vector of all the values in the given row index.
     /// Returns None if the index is out of range.
-    pub fn get_row(&self, index: usize) -> Option<Vec<f32>> {
+    pub fn get_row(&self, index: usize) -> Option<Vec<T>> {
         if index >= self.rows {
             return None;
         }
@@ -190,7 +572,7 @@ impl Matrix {
     ///
     /// Takes the index of the row, and returns a vector of all the values in the given row index.
     /// Returns None if the index is out of range.
-    pub fn get_row_mut(&mut self, index: usize) -> Option<&mut [f32]> {
+    pub fn get_row_mut(&mut self, index: usize) -> Option<&mut [T]> {
         if index >= self.rows {
             return None;
         }
@@ -204,7 +586,7 @@ impl Matrix {
     ///
     /// Takes the index of the row and returns a slice from the Matrix.
     /// Returns None if the index is out of range.
-    pub fn get_row_as_slice(&self, index: usize) -> Option<&[f32]> {
+    pub fn get_row_as_slice(&self, index: usize) -> Option<&[T]> {
         if index >= self.rows {
             return None;
         }
@@ -218,12 +600,12 @@ impl Matrix {
     ///
     /// Takes the index of the col, and returns a vector of all the values in the given col index.
     /// Returns None if the index is out of range.
-    pub fn get_col(&self, index: usize) -> Option<Vec<f32>> {
+    pub fn get_col(&self, index: usize) -> Option<Vec<T>> {
         if index >= self.cols {
             return None;
         }
 
-        let mut result: Vec<f32> = Vec::with_capacity(self.cols);
+        let mut result: Vec<T> = Vec::with_capacity(self.cols);
         for i in 0..self.rows {
             result.push(self.data[i * self.cols + index])
         }
@@ -234,35 +616,22 @@ impl Matrix {
     /// Get a single col from the Matrix (as a mutable reference)
     ///
     /// Takes the index of the col, and returns a vector of all the values in the given col index.
-    /// This code uses `unsafe`, and is therefor not recommended. Use `get_mut` if possible.
+    /// Use `iter_col_mut` to change the values without collecting them into a vector.
     /// Returns None if the index is out of range.
-    pub fn get_col_mut(&mut self, index: usize) -> Option<Vec<&mut f32>> {
-        if index >= self.cols {
-            return None;
-        }
-
-        let mut result: Vec<&mut f32> = Vec::with_capacity(self.cols);
-        let data_ptr = self.data.as_mut_ptr();
-        for i in 0..self.rows {
-            unsafe {
-                let elem = &mut *data_ptr.add(i * self.cols + index);
-                result.push(elem);
-            }
-        }
-
-        Some(result)
+    pub fn get_col_mut(&mut self, index: usize) -> Option<Vec<&mut T>> {
+        Some(self.iter_col_mut(index)?.collect())
     }
 
     /// Get a single row from the Matrix (as slice)
     ///
     /// Takes the index of the row and returns a slice from the Matrix.
     /// Returns None if the index is out of range.
-    pub fn get_col_as_slice(&self, index: usize) -> Option<Vec<&f32>> {
+    pub fn get_col_as_slice(&self, index: usize) -> Option<Vec<&T>> {
         if index >= self.cols {
             return None;
         }
 
-        let mut result: Vec<&f32> = Vec::with_capacity(self.cols);
+        let mut result: Vec<&T> = Vec::with_capacity(self.cols);
         for i in 0..self.rows {
             result.push(&self.data[i * self.cols + index])
         }
@@ -274,12 +643,12 @@ impl Matrix {
     ///
     /// Returns a new vector of all the numbers across the diagonal
     /// Returns none if the amount of rows is not equal to the amount of columns
-    pub fn get_diagonal(&self) -> Option<Vec<f32>> {
+    pub fn get_diagonal(&self) -> Option<Vec<T>> {
         if self.rows != self.cols {
             return None;
         }
 
-        let mut diagonal: Vec<f32> = Vec::new();
+        let mut diagonal: Vec<T> = Vec::new();
 
         for i in 0..self.rows {
             diagonal.push(self.data[i * self.cols + i]);
@@ -292,12 +661,12 @@ impl Matrix {
     ///
     /// Returns a new vector of all the numbers across the diagonal
     /// Returns none if the amount of rows is not equal to the amount of columns
-    pub fn get_diagonal_as_slice(&self) -> Option<Vec<&f32>> {
+    pub fn get_diagonal_as_slice(&self) -> Option<Vec<&T>> {
         if self.rows != self.cols {
             return None;
         }
 
-        let mut diagonal: Vec<&f32> = Vec::new();
+        let mut diagonal: Vec<&T> = Vec::new();
 
         for i in 0..self.rows {
             diagonal.push(&self.data[i * self.cols + i]);
@@ -312,12 +681,12 @@ impl Matrix {
     /// Not to be mistaken with the main diagonal.
     /// Returns a new vector of all the numbers across the diagonal
     /// Returns none if the amount of rows is not equal to the amount of columns
-    pub fn get_cross_diagonal(&self) -> Option<Vec<f32>> {
+    pub fn get_cross_diagonal(&self) -> Option<Vec<T>> {
         if self.rows != self.cols {
             return None;
         }
 
-        let mut diagonal: Vec<f32> = Vec::new();
+        let mut diagonal: Vec<T> = Vec::new();
 
         for i in 0..self.rows {
             let col_index = self.cols - i - 1;
@@ -333,12 +702,12 @@ impl Matrix {
     /// Not to be mistaken with the main diagonal.
     /// Returns a new vector of all the numbers across the diagonal
     /// Returns none if the amount of rows is not equal to the amount of columns
-    pub fn get_cross_diagonal_as_slice(&self) -> Option<Vec<&f32>> {
+    pub fn get_cross_diagonal_as_slice(&self) -> Option<Vec<&T>> {
         if self.rows != self.cols {
             return None;
         }
 
-        let mut diagonal: Vec<&f32> = Vec::new();
+        let mut diagonal: Vec<&T> = Vec::new();
 
         for i in 0..self.rows {
             let col_index = self.cols - i - 1;
@@ -348,6 +717,100 @@ impl Matrix {
         Some(diagonal)
     }
 
+    /// Iterate over the rows of the matrix
+    ///
+    /// Each item is a slice with the values of one row, from the first row to the last.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.rows).map(move |row| &self.data[row * self.cols..(row + 1) * self.cols])
+    }
+
+    /// Iterate over the rows of the matrix (as mutable slices)
+    ///
+    /// Each item is a mutable slice with the values of one row, from the first row to the last.
+    /// A matrix without columns gives one empty slice for each row, the same as `iter_rows`.
+    pub fn iter_rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let cols = self.cols;
+        let mut remaining = self.data.as_mut_slice();
+        (0..self.rows).map(move |_| {
+            let (row, rest) = std::mem::take(&mut remaining).split_at_mut(cols);
+            remaining = rest;
+            row
+        })
+    }
+
+    /// Iterate over the columns of the matrix
+    ///
+    /// Each item is an iterator over the values of one column, from the top to the bottom.
+    pub fn iter_cols(&self) -> impl Iterator<Item = StepBy<Skip<Iter<'_, T>>>> + '_ {
+        (0..self.cols).map(move |col| self.data.iter().skip(col).step_by(self.cols))
+    }
+
+    /// Iterate over the values of a single column (as mutable references)
+    ///
+    /// The values are given from the top to the bottom of the column.
+    /// Returns None if the index is out of range.
+    pub fn iter_col_mut(&mut self, index: usize) -> Option<StepBy<Skip<IterMut<'_, T>>>> {
+        if index >= self.cols {
+            return None;
+        }
+
+        Some(self.data.iter_mut().skip(index).step_by(self.cols))
+    }
+
+    /// Iterate over all values together with their position
+    ///
+    /// Each item is `((row, col), value)`, in row-major order, so the position can be used to index the matrix.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let cols = self.cols.max(1);
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(index, value)| ((index / cols, index % cols), value))
+    }
+
+    /// Create a new matrix by applying the function to each value
+    ///
+    /// The new matrix has the same shape, and may have another float type than this matrix.
+    /// For example, `matrix.map(|x| x.max(0.0))` applies the ReLU activation function.
+    pub fn map<U: Float, F: FnMut(T) -> U>(&self, mut operation: F) -> Matrix<U> {
+        Matrix {
+            data: self.data.iter().map(|x| operation(*x)).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Apply the function to each value of the matrix
+    ///
+    /// Mutates the matrix and replaces each value with the result of the function.
+    pub fn map_inplace<F: FnMut(T) -> T>(&mut self, mut operation: F) {
+        for item in self.data.iter_mut() {
+            *item = operation(*item);
+        }
+    }
+
+    /// Create a new matrix by applying the function to the values at the same position in both matrices
+    ///
+    /// The function gets the value of this matrix first, and the value of the given matrix second.
+    /// Returns `MatrixError::ShapeMismatch` if the matrices do not have the exact same shape.
+    pub fn zip_map<F: FnMut(T, T) -> T>(
+        &self,
+        mat: &Matrix<T>,
+        operation: F,
+    ) -> Result<Matrix<T>, MatrixError> {
+        self.zip_elementwise(mat, operation)
+    }
+
+    /// Apply the function to each row of the matrix
+    ///
+    /// The function gets each row as a mutable slice, so values can depend on the rest of the row,
+    /// for example to normalize each row so it sums to 1.
+    pub fn apply_rows<F: FnMut(&mut [T])>(&mut self, mut operation: F) {
+        for row in self.iter_rows_mut() {
+            operation(row);
+        }
+    }
+
     /// Get the shape of the Matrix.
     ///
     /// Format of the string is "ROWSxCOLUMNS". Created with the format macro.
@@ -365,7 +828,7 @@ impl Matrix {
 
         // Remove or add data to the matrix depending on the size difference
         if new_size > current_size {
-            self.data.resize(new_size, 0.0);
+            self.data.resize(new_size, T::ZERO);
         } else if new_size < current_size {
             self.data.truncate(new_size);
         }
@@ -375,33 +838,51 @@ impl Matrix {
         self.cols = new_cols;
     }
 
+    /// Convert the matrix to another float type
+    ///
+    /// Creates a new matrix with the same shape, for example a `Matrix<f64>` from a `Matrix<f32>`.
+    /// Converting to a type with less precision rounds each value to the nearest representable value.
+    pub fn cast<U: Float>(&self) -> Matrix<U> {
+        Matrix {
+            data: self.data.iter().map(|x| U::from_f64(x.to_f64())).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Check if two matrices are equal within the given tolerance
+    ///
+    /// The matrices are equal if they have the same shape, and the absolute difference of the values
+    /// at each position is at most `tolerance`. Useful in tests, where rounding errors make `==` too strict.
+    /// Returns false if any of the values is `NaN`.
+    pub fn approx_eq(&self, mat: &Matrix<T>, tolerance: T) -> bool {
+        self.rows == mat.rows
+            && self.cols == mat.cols
+            && self
+                .data
+                .iter()
+                .zip(mat.data.iter())
+                .all(|(a, b)| a == b || (*a - *b).abs() <= tolerance)
+    }
+
     /// Get a sub mutable matrix of the given matrix
     ///
     /// Ranges start from 0 and are not inclusive of the end value.
     /// Returns a `Result` with either the submatrix (`Matrix`) or the matrix error (`MatrixError`)
     /// Error that can occur is when the ranges are bigger than the shape of the `Matrix`
-    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix, MatrixError> {
-        // Check if the ranges fit the matrix
-        if rows.end > self.rows || cols.end > self.cols {
-            return Err(MatrixError::IllegalRange(format!(
-                "Did not match the shape: {}",
-                self.shape()
-            )));
-        }
-
-        // Check that the range is sequential and length at least 1
-        if rows.start >= rows.end || cols.start >= cols.end {
-            return Err(MatrixError::IllegalRange(
-                "Range must start go from low to high value, and length at least 1".to_string(),
-            ));
-        }
+    pub fn submatrix(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<Matrix<T>, MatrixError> {
+        check_region(&rows, &cols, self.rows, self.cols)?;
 
         // Format the data for the submatrix
-        let mut data: Vec<f32> = Vec::with_capacity(rows.len() * cols.len());
+        let mut data: Vec<T> = Vec::with_capacity(rows.len() * cols.len());
         for row in rows.clone() {
-            for col in cols.clone() {
-                data.push(self.data[row * self.rows + col].clone())
-            }
+            data.extend_from_slice(
+                &self.data[row * self.cols + cols.start..row * self.cols + cols.end],
+            );
         }
 
         Ok(Matrix {
@@ -411,107 +892,618 @@ impl Matrix {
         })
     }
 
-    /// Not Implemented
+    /// Get a submatrix of the given matrix that borrows the values instead of copying them
+    ///
+    /// Ranges start from 0 and are not inclusive of the end value.
+    /// Returns a `Result` with either the view (`MatrixView`) or the matrix error (`MatrixError`)
+    /// Same as `Matrix::view`, see `MatrixView` for the operations on the view.
     pub fn submatrix_as_slice(
         &self,
         rows: Range<usize>,
         cols: Range<usize>,
-    ) -> Result<Matrix, MatrixError> {
-        //TODO: Ignoring for now, not use case clear
-        unimplemented!()
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        self.view(rows, cols)
     }
 
-    /// Multiply two matrices
-    ///
-    /// Condition for multiplication of matrices:
-    /// - Given matrix `(mxn)` and `(qxp)`
-    /// - Columns `n` must equal rows `q`
+    /// Join matrices together along the given axis
     ///
-    /// Returns `Result` based on if this condition is met
-    pub fn multiply(&self, mat: &Matrix) -> Result<Matrix, MatrixError> {
-        // Check the matrix condition
-        if self.cols != mat.rows {
-            return Err(MatrixError::MatrixMultiply);
+    /// `Axis::Rows` places the matrices below each other, and requires the same amount of columns.
+    /// `Axis::Cols` places the matrices next to each other, and requires the same amount of rows.
+    /// This is the same as `numpy.concatenate` with `axis=0` and `axis=1`.
+    /// Returns `MatrixError::ShapeMismatch` with the first matrix that does not fit, or an empty `(0x0)` matrix if none are given.
+    pub fn concat(matrices: &[&Matrix<T>], axis: Axis) -> Result<Matrix<T>, MatrixError> {
+        let Some(first) = matrices.first() else {
+            return Ok(Matrix::new_typed(0, 0));
+        };
+
+        for mat in matrices {
+            let fits = match axis {
+                Axis::Rows => mat.cols == first.cols,
+                Axis::Cols => mat.rows == first.rows,
+            };
+            if !fits {
+                return Err(MatrixError::ShapeMismatch {
+                    first_matrix_shape: first.shape(),
+                    second_matrix_shape: mat.shape(),
+                });
+            }
         }
 
-        // Create the new sum
-        let mut matrix = Matrix::new(self.rows, mat.cols);
-
-        // Perform matrix multiplication
-        for i in 0..self.rows {
-            for j in 0..mat.cols {
-                let mut sum = 0.0;
-                for k in 0..self.cols {
-                    sum += self.data[i * self.cols + k] * mat.data[k * mat.cols + j];
+        let size = matrices.iter().map(|mat| mat.data.len()).sum();
+        let mut data: Vec<T> = Vec::with_capacity(size);
+        let (rows, cols) = match axis {
+            Axis::Rows => {
+                for mat in matrices {
+                    data.extend_from_slice(&mat.data);
                 }
-                matrix.data[i * mat.cols + j] = sum;
+                (matrices.iter().map(|mat| mat.rows).sum(), first.cols)
             }
-        }
+            Axis::Cols => {
+                for row in 0..first.rows {
+                    for mat in matrices {
+                        data.extend_from_slice(&mat.data[row * mat.cols..(row + 1) * mat.cols]);
+                    }
+                }
+                (first.rows, matrices.iter().map(|mat| mat.cols).sum())
+            }
+        };
 
-        Ok(matrix)
+        Ok(Matrix { data, rows, cols })
     }
 
-    /// Not implemented
-    pub fn transpose(&mut self) {
-        unimplemented!()
+    /// Place the given matrix to the right of this matrix
+    ///
+    /// Creates a new matrix with the columns of both matrices, same as `Matrix::concat` along `Axis::Cols`.
+    /// Returns `MatrixError::ShapeMismatch` if the matrices do not have the same amount of rows.
+    pub fn hstack(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        Matrix::concat(&[self, mat], Axis::Cols)
     }
 
-    /// Not implemented
-    pub fn get_transposed(&self) -> Matrix {
-        unimplemented!()
+    /// Place the given matrix below this matrix
+    ///
+    /// Creates a new matrix with the rows of both matrices, same as `Matrix::concat` along `Axis::Rows`.
+    /// Returns `MatrixError::ShapeMismatch` if the matrices do not have the same amount of columns.
+    pub fn vstack(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        Matrix::concat(&[self, mat], Axis::Rows)
     }
 
-    /// Not implemented
-    pub fn inverse(&mut self) {
-        unimplemented!()
-    }
+    /// Split the matrix into the rows before and after the given row index
+    ///
+    /// The first matrix has the rows `0..at`, and the second matrix has the rows `at..rows`.
+    /// Splitting at `0` or at the amount of rows gives an empty matrix as one of the parts.
+    /// Returns `MatrixError::IllegalRange` if the index is larger than the amount of rows.
+    pub fn split_rows(&self, at: usize) -> Result<(Matrix<T>, Matrix<T>), MatrixError> {
+        if at > self.rows {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not split a matrix with {} rows at row {at}",
+                self.rows
+            )));
+        }
 
-    /// Not implemented
-    pub fn get_inverse(&self) -> Matrix {
-        unimplemented!()
+        let (top, bottom) = self.data.split_at(at * self.cols);
+        Ok((
+            Matrix {
+                data: top.to_vec(),
+                rows: at,
+                cols: self.cols,
+            },
+            Matrix {
+                data: bottom.to_vec(),
+                rows: self.rows - at,
+                cols: self.cols,
+            },
+        ))
     }
 
-    /// Check if `Matrix` can act as a vector
+    /// Split the matrix into the columns before and after the given column index
     ///
-    /// Returns true if it contains one row or one column
-    pub fn is_vector(&self) -> bool {
-        return self.rows == 1 || self.cols == 1;
-    }
+    /// The first matrix has the columns `0..at`, and the second matrix has the columns `at..cols`.
+    /// Useful for separating the features from the labels of a dataset.
+    /// Returns `MatrixError::IllegalRange` if the index is larger than the amount of columns.
+    pub fn split_cols(&self, at: usize) -> Result<(Matrix<T>, Matrix<T>), MatrixError> {
+        if at > self.cols {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not split a matrix with {} columns at column {at}",
+                self.cols
+            )));
+        }
 
-    /// Subtracts all values in the matrix by a given number (`f32`)
-    ///
-    /// Mutates the matrix and makes the change.
-    pub fn sub_f(&mut self, numb: f32) {
-        for item in self.data.iter_mut() {
-            *item -= numb;
+        let mut left: Vec<T> = Vec::with_capacity(self.rows * at);
+        let mut right: Vec<T> = Vec::with_capacity(self.rows * (self.cols - at));
+        for row in self.data.chunks_exact(self.cols.max(1)) {
+            left.extend_from_slice(&row[..at]);
+            right.extend_from_slice(&row[at..]);
         }
+
+        Ok((
+            Matrix {
+                data: left,
+                rows: self.rows,
+                cols: at,
+            },
+            Matrix {
+                data: right,
+                rows: self.rows,
+                cols: self.cols - at,
+            },
+        ))
     }
 
-    /// Add a number (`f32`) to all values in the matrix
+    /// Insert a row at the given index
     ///
-    /// Mutates the matrix and makes the change.
-    pub fn add_f(&mut self, numb: f32) {
-        for item in self.data.iter_mut() {
-            *item += numb;
+    /// The rows from the index and onwards are moved down by one. Inserting at the amount of rows appends the row.
+    /// Returns `MatrixError::IllegalRange` if the index is larger than the amount of rows,
+    /// and `MatrixError::ShapeMismatch` if the length of the row does not equal the amount of columns.
+    pub fn insert_row(&mut self, index: usize, row: &[T]) -> Result<(), MatrixError> {
+        if index > self.rows {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not insert row {index} into a matrix with {} rows",
+                self.rows
+            )));
         }
+        if row.len() != self.cols {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.shape(),
+                second_matrix_shape: format!("1x{}", row.len()),
+            });
+        }
+
+        let start = index * self.cols;
+        self.data.splice(start..start, row.iter().copied());
+        self.rows += 1;
+        Ok(())
     }
 
-    /// Scale all values in the matrix by a given scalar (`f32`)
+    /// Insert a column at the given index
     ///
-    /// Mutates the matrix and makes the change.
-    pub fn scale_f(&mut self, numb: f32) {
-        for item in self.data.iter_mut() {
-            *item *= numb;
+    /// The columns from the index and onwards are moved right by one. Inserting at the amount of columns appends the column.
+    /// For example, `insert_col(0, &vec![1.0; matrix.rows])` adds a bias column of ones to a design matrix.
+    /// Returns `MatrixError::IllegalRange` if the index is larger than the amount of columns,
+    /// and `MatrixError::ShapeMismatch` if the length of the column does not equal the amount of rows.
+    pub fn insert_col(&mut self, index: usize, col: &[T]) -> Result<(), MatrixError> {
+        if index > self.cols {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not insert column {index} into a matrix with {} columns",
+                self.cols
+            )));
+        }
+        if col.len() != self.rows {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.shape(),
+                second_matrix_shape: format!("{}x1", col.len()),
+            });
+        }
+
+        let mut data: Vec<T> = Vec::with_capacity(self.rows * (self.cols + 1));
+        for (row, value) in col.iter().enumerate() {
+            let start = row * self.cols;
+            data.extend_from_slice(&self.data[start..start + index]);
+            data.push(*value);
+            data.extend_from_slice(&self.data[start + index..start + self.cols]);
+        }
+
+        self.data = data;
+        self.cols += 1;
+        Ok(())
+    }
+
+    /// Remove the row at the given index
+    ///
+    /// The rows after the index are moved up by one.
+    /// Returns the values of the removed row, or `MatrixError::IllegalRange` if the index is out of range.
+    pub fn remove_row(&mut self, index: usize) -> Result<Vec<T>, MatrixError> {
+        if index >= self.rows {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not remove row {index} from a matrix with {} rows",
+                self.rows
+            )));
+        }
+
+        let start = index * self.cols;
+        let removed: Vec<T> = self.data.drain(start..start + self.cols).collect();
+        self.rows -= 1;
+        Ok(removed)
+    }
+
+    /// Remove the column at the given index
+    ///
+    /// The columns after the index are moved left by one.
+    /// Returns the values of the removed column, or `MatrixError::IllegalRange` if the index is out of range.
+    pub fn remove_col(&mut self, index: usize) -> Result<Vec<T>, MatrixError> {
+        if index >= self.cols {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not remove column {index} from a matrix with {} columns",
+                self.cols
+            )));
+        }
+
+        let mut removed: Vec<T> = Vec::with_capacity(self.rows);
+        let mut data: Vec<T> = Vec::with_capacity(self.rows * (self.cols - 1));
+        for row in self.data.chunks_exact(self.cols) {
+            data.extend_from_slice(&row[..index]);
+            removed.push(row[index]);
+            data.extend_from_slice(&row[index + 1..]);
+        }
+
+        self.data = data;
+        self.cols -= 1;
+        Ok(removed)
+    }
+
+    /// Multiply two matrices
+    ///
+    /// Condition for multiplication of matrices:
+    /// - Given matrix `(mxn)` and `(qxp)`
+    /// - Columns `n` must equal rows `q`
+    ///
+    /// Returns `Result` based on if this condition is met
+    ///
+    /// Uses a tiled kernel on the transposed `mat`, and splits the rows across all available cores for large matrices.
+    /// See `Matrix::multiply_with_threads` to choose the amount of threads.
+    pub fn multiply(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let threads = if self.rows * self.cols * mat.cols < MULTIPLY_PARALLEL_THRESHOLD {
+            1
+        } else {
+            std::thread::available_parallelism().map_or(1, |threads| threads.get())
+        };
+
+        self.multiply_with_threads(mat, threads)
+    }
+
+    /// Multiply two matrices, splitting the rows of the result across the given amount of threads
+    ///
+    /// Same condition as `Matrix::multiply`, the columns of this matrix must equal the rows of `mat`.
+    /// The given matrix is transposed first, so both matrices are read row by row in the inner loop.
+    /// The result is computed in square tiles that fit in the cache, and each thread computes a band of rows.
+    /// A thread count of `0` is treated as `1`, and no more threads than rows are started.
+    pub fn multiply_with_threads(
+        &self,
+        mat: &Matrix<T>,
+        threads: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        // Check the matrix condition
+        if self.cols != mat.rows {
+            return Err(MatrixError::MatrixMultiply);
+        }
+
+        let mut matrix = Matrix::new_typed(self.rows, mat.cols);
+        if matrix.data.is_empty() || self.cols == 0 {
+            return Ok(matrix);
+        }
+
+        let mat_transposed = mat.get_transposed();
+        let threads = threads.clamp(1, self.rows);
+        let rows_per_thread = self.rows.div_ceil(threads);
+
+        if threads == 1 {
+            multiply_block(
+                &self.data,
+                &mat_transposed.data,
+                &mut matrix.data,
+                self.cols,
+            );
+        } else {
+            std::thread::scope(|scope| {
+                for (band, result) in matrix
+                    .data
+                    .chunks_mut(rows_per_thread * mat.cols)
+                    .enumerate()
+                {
+                    let start = band * rows_per_thread * self.cols;
+                    let end = start + result.len() / mat.cols * self.cols;
+                    let lhs = &self.data[start..end];
+                    let rhs = &mat_transposed.data;
+                    scope.spawn(move || multiply_block(lhs, rhs, result, self.cols));
+                }
+            });
+        }
+
+        Ok(matrix)
+    }
+
+    /// Multiply two matrices with the textbook triple loop
+    ///
+    /// Same result as `Matrix::multiply`, but without tiling or threads.
+    /// Mostly useful as a reference when testing and benchmarking.
+    pub fn multiply_naive(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        // Check the matrix condition
+        if self.cols != mat.rows {
+            return Err(MatrixError::MatrixMultiply);
+        }
+
+        // Create the new sum
+        let mut matrix = Matrix::new_typed(self.rows, mat.cols);
+
+        // Perform matrix multiplication
+        for i in 0..self.rows {
+            for j in 0..mat.cols {
+                let mut sum = T::ZERO;
+                for k in 0..self.cols {
+                    sum += self.data[i * self.cols + k] * mat.data[k * mat.cols + j];
+                }
+                matrix.data[i * mat.cols + j] = sum;
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    /// Transpose the matrix in place
+    ///
+    /// Swaps rows and columns, so a `(mxn)` matrix becomes a `(nxm)` matrix.
+    /// Square matrices are transposed by swapping values across the main diagonal, tile by tile.
+    /// Non-square matrices are transposed by following the permutation cycles of the data.
+    /// Read more about in-place transposition: <https://en.wikipedia.org/wiki/In-place_matrix_transposition>
+    pub fn transpose(&mut self) {
+        if self.rows == self.cols {
+            self.transpose_square_in_place();
+        } else {
+            self.transpose_cycles_in_place();
+        }
+
+        std::mem::swap(&mut self.rows, &mut self.cols);
+    }
+
+    /// Get a transposed copy of the matrix
+    ///
+    /// Creates a new `(nxm)` matrix from the `(mxn)` matrix.
+    /// The copy is done in square tiles, so both the reads and writes stay cache friendly for large matrices.
+    pub fn get_transposed(&self) -> Matrix<T> {
+        let mut data: Vec<T> = vec![T::ZERO; self.data.len()];
+
+        for row_block in (0..self.rows).step_by(TRANSPOSE_BLOCK_SIZE) {
+            let row_end = usize::min(row_block + TRANSPOSE_BLOCK_SIZE, self.rows);
+            for col_block in (0..self.cols).step_by(TRANSPOSE_BLOCK_SIZE) {
+                let col_end = usize::min(col_block + TRANSPOSE_BLOCK_SIZE, self.cols);
+
+                // Transpose the values within the current tile
+                for row in row_block..row_end {
+                    for col in col_block..col_end {
+                        data[col * self.rows + row] = self.data[row * self.cols + col];
+                    }
+                }
+            }
+        }
+
+        Matrix {
+            data,
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Transpose a square matrix in place, one tile at a time
+    fn transpose_square_in_place(&mut self) {
+        let order = self.rows;
+
+        for row_block in (0..order).step_by(TRANSPOSE_BLOCK_SIZE) {
+            let row_end = usize::min(row_block + TRANSPOSE_BLOCK_SIZE, order);
+
+            // Only visit the tiles on and above the main diagonal
+            for col_block in (row_block..order).step_by(TRANSPOSE_BLOCK_SIZE) {
+                let col_end = usize::min(col_block + TRANSPOSE_BLOCK_SIZE, order);
+
+                for row in row_block..row_end {
+                    // Within a diagonal tile, start right of the diagonal to avoid swapping twice
+                    let col_start = if row_block == col_block {
+                        row + 1
+                    } else {
+                        col_block
+                    };
+
+                    for col in col_start..col_end {
+                        self.data.swap(row * order + col, col * order + row);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Transpose a non-square matrix in place by following each permutation cycle
+    ///
+    /// The value at index `i` of a `(mxn)` matrix moves to index `(i * m) % (m * n - 1)`.
+    /// Each cycle is rotated once, and a vector of flags keeps track of the visited indexes.
+    fn transpose_cycles_in_place(&mut self) {
+        let size = self.data.len();
+        if size < 2 {
+            return;
+        }
+
+        let last = size - 1;
+        let mut visited: Vec<bool> = vec![false; size];
+
+        // The first and last value never move
+        for start in 1..last {
+            if visited[start] {
+                continue;
+            }
+
+            let mut index = start;
+            let mut carried = self.data[start];
+            loop {
+                let next = (index * self.rows) % last;
+                std::mem::swap(&mut self.data[next], &mut carried);
+                visited[next] = true;
+                index = next;
+
+                if index == start {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Get the LU decomposition of the matrix
+    ///
+    /// See `LuDecomposition` for the factors, determinant and linear system solver.
+    /// Returns `MatrixError::NotSquare` if the matrix is not square.
+    pub fn lu(&self) -> Result<LuDecomposition<T>, MatrixError> {
+        LuDecomposition::new(self)
+    }
+
+    /// Calculate the determinant of the matrix
+    ///
+    /// Uses LU decomposition with partial pivoting, and works for square matrices of any size.
+    /// Returns `MatrixError::NotSquare` if the matrix is not square.
+    pub fn determinant(&self) -> Result<T, MatrixError> {
+        Ok(self.lu()?.determinant())
+    }
+
+    /// Invert the matrix
+    ///
+    /// Mutates the matrix and replaces it with its inverse.
+    /// Returns `MatrixError::NotSquare` or `MatrixError::SingularMatrix` if the matrix has no inverse.
+    /// The matrix is left unchanged if an error is returned.
+    pub fn inverse(&mut self) -> Result<(), MatrixError> {
+        let inverse = self.get_inverse()?;
+        self.data = inverse.data;
+        Ok(())
+    }
+
+    /// Get the inverse of the matrix
+    ///
+    /// Creates a new matrix `A^-1` such that `A * A^-1 = I`. Uses LU decomposition with partial pivoting.
+    /// Returns `MatrixError::NotSquare` or `MatrixError::SingularMatrix` if the matrix has no inverse.
+    pub fn get_inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.lu()?.inverse()
+    }
+
+    /// Get the QR decomposition of the matrix
+    ///
+    /// See `QrDecomposition` for the factors and the least squares solver.
+    pub fn qr(&self) -> QrDecomposition<T> {
+        QrDecomposition::new(self)
+    }
+
+    /// Get the Cholesky decomposition of the matrix
+    ///
+    /// See `Cholesky` for the factor and the linear system solver.
+    /// Returns an error if the matrix is not symmetric and positive-definite.
+    pub fn cholesky(&self) -> Result<Cholesky<T>, MatrixError> {
+        Cholesky::new(self)
+    }
+
+    /// Get the eigendecomposition of a symmetric matrix
+    ///
+    /// See `SymmetricEigen` for the eigenvalues and eigenvectors.
+    /// Returns an error if the matrix is not symmetric.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MatrixError> {
+        SymmetricEigen::new(self)
+    }
+
+    /// Get the thin singular value decomposition of the matrix
+    ///
+    /// See `Svd` for the factors and the values derived from them.
+    pub fn svd(&self) -> Result<Svd<T>, MatrixError> {
+        Svd::new(self)
+    }
+
+    /// Get the full singular value decomposition of the matrix
+    ///
+    /// Same as `svd`, but `U` and `V^T` are square matrices.
+    pub fn svd_full(&self) -> Result<Svd<T>, MatrixError> {
+        Svd::full(self)
+    }
+
+    /// Calculate the Moore-Penrose pseudo-inverse of the matrix
+    ///
+    /// Exists for all matrices, also singular and non-square matrices. Computed with SVD.
+    /// The pseudo-inverse of a `(mxn)` matrix has shape `(nxm)`.
+    pub fn pseudo_inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        Ok(self.svd()?.pseudo_inverse())
+    }
+
+    /// Calculate the rank of the matrix
+    ///
+    /// The rank is the amount of linearly independent rows or columns. Computed with SVD.
+    pub fn rank(&self) -> Result<usize, MatrixError> {
+        Ok(self.svd()?.rank())
+    }
+
+    /// Calculate the condition number of the matrix
+    ///
+    /// The ratio between the largest and smallest singular value. Large values mean that solving
+    /// a linear system with the matrix is sensitive to rounding errors.
+    /// Returns `T::INFINITY` for a singular matrix.
+    pub fn condition_number(&self) -> Result<T, MatrixError> {
+        Ok(self.svd()?.condition_number())
+    }
+
+    /// Get the best approximation of the matrix with the given rank
+    ///
+    /// Keeps only the `rank` largest singular values of the SVD.
+    /// Returns `MatrixError::IllegalRange` if `rank` is larger than `min(rows, cols)`.
+    pub fn low_rank_approximation(&self, rank: usize) -> Result<Matrix<T>, MatrixError> {
+        self.svd()?.low_rank_approximation(rank)
+    }
+
+    /// Solve the linear system `AX = B` for `X`
+    ///
+    /// The matrix `A` must be square. Each column of `B` is a right-hand side, so several systems can be solved at once.
+    /// Uses QR decomposition with Householder reflections.
+    ///
+    /// Returns `MatrixError::NotSquare` if `A` is not square, `MatrixError::ShapeMismatch` if `B` does not have
+    /// the same amount of rows as `A`, and `MatrixError::RankDeficient` if `A` is singular.
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare(self.shape()));
+        }
+
+        self.qr().solve(rhs)
+    }
+
+    /// Solve the overdetermined linear system `AX = B` with least squares
+    ///
+    /// Finds the `X` that minimizes the norm of `AX - B`, where `A` has at least as many rows as columns.
+    /// Each column of `B` is a right-hand side. Uses QR decomposition with Householder reflections.
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if `B` does not have the same amount of rows as `A`,
+    /// and `MatrixError::RankDeficient` if `A` does not have full column rank.
+    pub fn lstsq(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.qr().solve(rhs)
+    }
+
+    /// Check if `Matrix` can act as a vector
+    ///
+    /// Returns true if it contains one row or one column
+    pub fn is_vector(&self) -> bool {
+        return self.rows == 1 || self.cols == 1;
+    }
+
+    /// Subtracts all values in the matrix by a given number (`T`)
+    ///
+    /// Mutates the matrix and makes the change.
+    pub fn sub_f(&mut self, numb: T) {
+        for item in self.data.iter_mut() {
+            *item -= numb;
+        }
+    }
+
+    /// Add a number (`f32`) to all values in the matrix
+    ///
+    /// Mutates the matrix and makes the change.
+    pub fn add_f(&mut self, numb: T) {
+        for item in self.data.iter_mut() {
+            *item += numb;
         }
     }
 
-    /// Scale all values in the matrix by a given scalar (`f32`)
+    /// Scale all values in the matrix by a given scalar (`T`)
+    ///
+    /// Mutates the matrix and makes the change.
+    pub fn scale_f(&mut self, numb: T) {
+        for item in self.data.iter_mut() {
+            *item *= numb;
+        }
+    }
+
+    /// Scale all values in the matrix by a given scalar (`T`)
     ///
     /// Mutates the matrix and makes the change. Checks for division by 0.
     /// Returns a result based on this condition  
-    pub fn div_f(&mut self, numb: f32) -> Result<(), MatrixError> {
+    pub fn div_f(&mut self, numb: T) -> Result<(), MatrixError> {
         // Check for divide by 0 error
-        if numb == 0.0 {
+        if numb == T::ZERO {
             return Err(MatrixError::DivideByZero);
         }
 
@@ -522,24 +1514,863 @@ impl Matrix {
         Ok(())
     }
 
-    /// Scale all values in the matrix by a given scalar (`f32`)
+    /// Add two matrices element-wise
+    ///
+    /// Creates a new matrix where each value is the sum of the values at the same position.
+    /// Returns `MatrixError::ShapeMismatch` if the matrices do not have the exact same shape.
+    pub fn add_elementwise(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_elementwise(mat, |a, b| a + b)
+    }
+
+    /// Subtract two matrices element-wise
+    ///
+    /// Creates a new matrix where each value is the difference of the values at the same position.
+    /// Returns `MatrixError::ShapeMismatch` if the matrices do not have the exact same shape.
+    pub fn sub_elementwise(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_elementwise(mat, |a, b| a - b)
+    }
+
+    /// Multiply two matrices element-wise (Hadamard product)
+    ///
+    /// Creates a new matrix where each value is the product of the values at the same position.
+    /// Not to be mistaken with matrix multiplication, see `multiply`.
+    /// Returns `MatrixError::ShapeMismatch` if the matrices do not have the exact same shape.
+    pub fn mul_elementwise(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_elementwise(mat, |a, b| a * b)
+    }
+
+    /// Divide two matrices element-wise
+    ///
+    /// Creates a new matrix where each value is the quotient of the values at the same position.
+    /// Returns `MatrixError::ShapeMismatch` if the matrices do not have the exact same shape,
+    /// and `MatrixError::DivideByZero` if the given matrix contains a zero.
+    pub fn div_elementwise(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_same_shape(mat)?;
+        if mat.data.contains(&T::ZERO) {
+            return Err(MatrixError::DivideByZero);
+        }
+
+        self.zip_elementwise(mat, |a, b| a / b)
+    }
+
+    /// Add two matrices element-wise, with broadcasting
+    ///
+    /// Follows the broadcasting rules of NumPy, see `broadcast_shape`.
+    /// This makes it possible to add a row vector to every row, or a column vector to every column.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_add(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.broadcast_elementwise(mat, |a, b| a + b)
+    }
+
+    /// Subtract two matrices element-wise, with broadcasting
+    ///
+    /// Follows the broadcasting rules of NumPy, see `broadcast_shape`.
+    /// Subtracting a row vector of column means centers every column.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_sub(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.broadcast_elementwise(mat, |a, b| a - b)
+    }
+
+    /// Multiply two matrices element-wise, with broadcasting
+    ///
+    /// Follows the broadcasting rules of NumPy, see `broadcast_shape`.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_mul(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.broadcast_elementwise(mat, |a, b| a * b)
+    }
+
+    /// Divide two matrices element-wise, with broadcasting
+    ///
+    /// Follows the broadcasting rules of NumPy, see `broadcast_shape`.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together,
+    /// and `MatrixError::DivideByZero` if the given matrix contains a zero.
+    pub fn broadcast_div(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.broadcast_shape(mat)?;
+        if mat.data.contains(&T::ZERO) {
+            return Err(MatrixError::DivideByZero);
+        }
+
+        self.broadcast_elementwise(mat, |a, b| a / b)
+    }
+
+    /// Get the shape of the result when broadcasting two matrices together
+    ///
+    /// The rows, and the columns, are compatible if they are equal or if one of them is 1.
+    /// A dimension of size 1 is stretched to match the other matrix.
+    /// Read more about broadcasting: <https://numpy.org/doc/stable/user/basics.broadcasting.html>
+    ///
+    /// Returns the `(rows, cols)` of the result, or `MatrixError::ShapeMismatch` if the shapes are not compatible.
+    pub fn broadcast_shape(&self, mat: &Matrix<T>) -> Result<(usize, usize), MatrixError> {
+        let broadcast_dimension = |first: usize, second: usize| -> Option<usize> {
+            if first == second || second == 1 {
+                Some(first)
+            } else if first == 1 {
+                Some(second)
+            } else {
+                None
+            }
+        };
+
+        match (
+            broadcast_dimension(self.rows, mat.rows),
+            broadcast_dimension(self.cols, mat.cols),
+        ) {
+            (Some(rows), Some(cols)) => Ok((rows, cols)),
+            _ => Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.shape(),
+                second_matrix_shape: mat.shape(),
+            }),
+        }
+    }
+
+    /// Combine two matrices value by value, stretching dimensions of size 1
+    fn broadcast_elementwise<F>(
+        &self,
+        mat: &Matrix<T>,
+        operation: F,
+    ) -> Result<Matrix<T>, MatrixError>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let (rows, cols) = self.broadcast_shape(mat)?;
+        let mut data: Vec<T> = Vec::with_capacity(rows * cols);
+
+        for row in 0..rows {
+            // A dimension of size 1 always reads from index 0
+            let first_row = if self.rows == 1 { 0 } else { row };
+            let second_row = if mat.rows == 1 { 0 } else { row };
+
+            for col in 0..cols {
+                let first_col = if self.cols == 1 { 0 } else { col };
+                let second_col = if mat.cols == 1 { 0 } else { col };
+
+                data.push(operation(
+                    self.data[first_row * self.cols + first_col],
+                    mat.data[second_row * mat.cols + second_col],
+                ));
+            }
+        }
+
+        Ok(Matrix { data, rows, cols })
+    }
+
+    /// Panic if the given row and column is outside of the matrix
+    fn check_index(&self, row: usize, col: usize) {
+        if row >= self.rows || col >= self.cols {
+            panic!(
+                "index out of bounds: the matrix has shape {} but the index is ({row}, {col})",
+                self.shape()
+            );
+        }
+    }
+
+    /// Check that the given matrix has the exact same shape
+    fn check_same_shape(&self, mat: &Matrix<T>) -> Result<(), MatrixError> {
+        if self.rows != mat.rows || self.cols != mat.cols {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.shape(),
+                second_matrix_shape: mat.shape(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Combine two matrices of the same shape value by value
+    fn zip_elementwise<F>(
+        &self,
+        mat: &Matrix<T>,
+        mut operation: F,
+    ) -> Result<Matrix<T>, MatrixError>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.check_same_shape(mat)?;
+
+        let data: Vec<T> = self
+            .data
+            .iter()
+            .zip(mat.data.iter())
+            .map(|(a, b)| operation(*a, *b))
+            .collect();
+
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Combine the given matrix into this matrix value by value
+    ///
+    /// Panics if the matrices do not have the exact same shape. Used by the assign operators.
+    fn zip_elementwise_in_place<F>(&mut self, mat: &Matrix<T>, operation: F)
+    where
+        F: Fn(&mut T, T),
+    {
+        if let Err(err) = self.check_same_shape(mat) {
+            panic!("{err}");
+        }
+
+        for (a, b) in self.data.iter_mut().zip(mat.data.iter()) {
+            operation(a, *b);
+        }
+    }
+
+    /// Scale all values in the matrix by a given scalar (`T`)
     ///
     /// Mutates the matrix and makes the change.
-    pub fn mod_f(&mut self, numb: f32) {
+    pub fn mod_f(&mut self, numb: T) {
         for item in self.data.iter_mut() {
             *item %= numb;
         }
     }
 
+    /// Sum all the values in the matrix
+    pub fn sum(&self) -> T {
+        self.data.iter().sum()
+    }
+
+    /// Calculate the mean of all the values in the matrix
+    ///
+    /// Returns `NaN` if the matrix has no values.
+    pub fn mean(&self) -> T {
+        self.sum() / T::from_usize(self.data.len())
+    }
+
+    /// Calculate the trace of the matrix
+    ///
+    /// The trace is the sum of the values on the main diagonal.
+    /// Returns `MatrixError::NotSquare` if the matrix is not square.
+    pub fn trace(&self) -> Result<T, MatrixError> {
+        match self.get_diagonal() {
+            Some(diagonal) => Ok(diagonal.iter().sum()),
+            None => Err(MatrixError::NotSquare(self.shape())),
+        }
+    }
+
+    /// Calculate the given norm of the matrix
+    ///
+    /// See `Norm` for the supported norms.
+    pub fn norm(&self, norm: Norm) -> T {
+        match norm {
+            Norm::Frobenius => self.data.iter().map(|x| *x * *x).sum::<T>().sqrt(),
+            Norm::L1 => self
+                .abs_sum_axis(Axis::Rows)
+                .data
+                .into_iter()
+                .fold(T::ZERO, T::max),
+            Norm::Infinity => self
+                .abs_sum_axis(Axis::Cols)
+                .data
+                .into_iter()
+                .fold(T::ZERO, T::max),
+        }
+    }
+
+    /// Sum the values along the given axis
+    ///
+    /// Returns a row vector with the sum of each column for `Axis::Rows`,
+    /// and a column vector with the sum of each row for `Axis::Cols`.
+    pub fn sum_axis(&self, axis: Axis) -> Matrix<T> {
+        self.fold_axis(axis, T::ZERO, |sum, x| sum + x)
+    }
+
+    /// Calculate the mean of the values along the given axis
+    ///
+    /// Returns a row vector with the mean of each column for `Axis::Rows`,
+    /// and a column vector with the mean of each row for `Axis::Cols`.
+    pub fn mean_axis(&self, axis: Axis) -> Matrix<T> {
+        let count = T::from_usize(self.axis_length(axis));
+        let mut mean = self.sum_axis(axis);
+        for item in mean.data.iter_mut() {
+            *item /= count;
+        }
+        mean
+    }
+
+    /// Calculate the variance of the values along the given axis
+    ///
+    /// The sum of squared differences from the mean is divided by `n - ddof`, where `n` is the amount of values.
+    /// Use `ddof = 0` for the population variance and `ddof = 1` for the sample variance.
+    /// Returns a row vector for `Axis::Rows`, and a column vector for `Axis::Cols`.
+    pub fn var_axis(&self, axis: Axis, ddof: usize) -> Matrix<T> {
+        let mean = self.mean_axis(axis);
+        let divisor = T::from_usize(self.axis_length(axis).saturating_sub(ddof));
+
+        let mut variance = match axis {
+            Axis::Rows => {
+                let mut squares = Matrix::new_typed(1, self.cols);
+                for row in self.data.chunks_exact(self.cols.max(1)) {
+                    for ((square, x), m) in squares.data.iter_mut().zip(row).zip(&mean.data) {
+                        *square += (*x - *m) * (*x - *m);
+                    }
+                }
+                squares
+            }
+            Axis::Cols => {
+                let mut squares = Matrix::new_typed(self.rows, 1);
+                for (row, square) in squares.data.iter_mut().enumerate() {
+                    let m = mean.data[row];
+                    *square = self.data[row * self.cols..(row + 1) * self.cols]
+                        .iter()
+                        .map(|x| (*x - m) * (*x - m))
+                        .sum();
+                }
+                squares
+            }
+        };
+
+        for item in variance.data.iter_mut() {
+            *item /= divisor;
+        }
+        variance
+    }
+
+    /// Calculate the standard deviation of the values along the given axis
+    ///
+    /// The square root of `var_axis`, see it for the meaning of `ddof`.
+    /// Returns a row vector for `Axis::Rows`, and a column vector for `Axis::Cols`.
+    pub fn std_axis(&self, axis: Axis, ddof: usize) -> Matrix<T> {
+        let mut deviation = self.var_axis(axis, ddof);
+        for item in deviation.data.iter_mut() {
+            *item = item.sqrt();
+        }
+        deviation
+    }
+
+    /// Find the smallest value along the given axis
+    ///
+    /// Returns a row vector for `Axis::Rows`, and a column vector for `Axis::Cols`.
+    /// If the reduced axis has no values, every item is `T::INFINITY`, the identity of `min`.
+    /// Use `argmin_axis` to get an error for an empty axis instead.
+    pub fn min_axis(&self, axis: Axis) -> Matrix<T> {
+        self.fold_axis(axis, T::INFINITY, T::min)
+    }
+
+    /// Find the largest value along the given axis
+    ///
+    /// Returns a row vector for `Axis::Rows`, and a column vector for `Axis::Cols`.
+    /// If the reduced axis has no values, every item is `T::NEG_INFINITY`, the identity of `max`.
+    /// Use `argmax_axis` to get an error for an empty axis instead.
+    pub fn max_axis(&self, axis: Axis) -> Matrix<T> {
+        self.fold_axis(axis, T::NEG_INFINITY, T::max)
+    }
+
+    /// Find the index of the largest value along the given axis
+    ///
+    /// For `Axis::Rows` it returns the row index of the largest value in each column,
+    /// and for `Axis::Cols` the column index of the largest value in each row.
+    /// The first index is used if several values are equal.
+    /// With class scores in each row, `argmax_axis(Axis::Cols)` gives the predicted class of each row.
+    /// Returns a row vector for `Axis::Rows`, and a column vector for `Axis::Cols`.
+    /// Returns `MatrixError::IllegalRange` if the reduced axis has no values.
+    pub fn argmax_axis(&self, axis: Axis) -> Result<Matrix<T>, MatrixError> {
+        self.arg_select_axis(axis, |candidate, best| candidate > best)
+    }
+
+    /// Find the index of the smallest value along the given axis
+    ///
+    /// For `Axis::Rows` it returns the row index of the smallest value in each column,
+    /// and for `Axis::Cols` the column index of the smallest value in each row.
+    /// The first index is used if several values are equal.
+    /// Returns a row vector for `Axis::Rows`, and a column vector for `Axis::Cols`.
+    /// Returns `MatrixError::IllegalRange` if the reduced axis has no values.
+    pub fn argmin_axis(&self, axis: Axis) -> Result<Matrix<T>, MatrixError> {
+        self.arg_select_axis(axis, |candidate, best| candidate < best)
+    }
+
+    /// Get the amount of values that are reduced along the given axis
+    fn axis_length(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Rows => self.rows,
+            Axis::Cols => self.cols,
+        }
+    }
+
+    /// Sum the absolute values along the given axis
+    fn abs_sum_axis(&self, axis: Axis) -> Matrix<T> {
+        self.fold_axis(axis, T::ZERO, |sum, x| sum + x.abs())
+    }
+
+    /// Fold the values along the given axis into a vector
+    ///
+    /// Visits the values in the order they are stored, so both axes are cache friendly.
+    fn fold_axis<F>(&self, axis: Axis, init: T, operation: F) -> Matrix<T>
+    where
+        F: Fn(T, T) -> T,
+    {
+        match axis {
+            Axis::Rows => {
+                let mut result = Matrix {
+                    data: vec![init; self.cols],
+                    rows: 1,
+                    cols: self.cols,
+                };
+                for row in self.data.chunks_exact(self.cols.max(1)) {
+                    for (acc, x) in result.data.iter_mut().zip(row) {
+                        *acc = operation(*acc, *x);
+                    }
+                }
+                result
+            }
+            Axis::Cols => {
+                let data: Vec<T> = (0..self.rows)
+                    .map(|row| {
+                        self.data[row * self.cols..(row + 1) * self.cols]
+                            .iter()
+                            .fold(init, |acc, x| operation(acc, *x))
+                    })
+                    .collect();
+                Matrix {
+                    data,
+                    rows: self.rows,
+                    cols: 1,
+                }
+            }
+        }
+    }
+
+    /// Find the index of the selected value along the given axis
+    ///
+    /// `is_better` decides if a candidate value should replace the current best value.
+    fn arg_select_axis<F>(&self, axis: Axis, is_better: F) -> Result<Matrix<T>, MatrixError>
+    where
+        F: Fn(T, T) -> bool,
+    {
+        let (outer, inner) = match axis {
+            Axis::Rows => (self.cols, self.rows),
+            Axis::Cols => (self.rows, self.cols),
+        };
+        if inner == 0 {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not find an index along an empty axis of a {} matrix",
+                self.shape()
+            )));
+        }
+        let value_at = |outer_index: usize, inner_index: usize| match axis {
+            Axis::Rows => self.data[inner_index * self.cols + outer_index],
+            Axis::Cols => self.data[outer_index * self.cols + inner_index],
+        };
+
+        let data: Vec<T> = (0..outer)
+            .map(|outer_index| {
+                let mut best_index = 0;
+                for inner_index in 1..inner {
+                    if is_better(
+                        value_at(outer_index, inner_index),
+                        value_at(outer_index, best_index),
+                    ) {
+                        best_index = inner_index;
+                    }
+                }
+                T::from_usize(best_index)
+            })
+            .collect();
+
+        Ok(match axis {
+            Axis::Rows => Matrix {
+                data,
+                rows: 1,
+                cols: outer,
+            },
+            Axis::Cols => Matrix {
+                data,
+                rows: outer,
+                cols: 1,
+            },
+        })
+    }
+
+    /// Check if the matrix is orthogonal
+    ///
+    /// A square matrix `Q` is orthogonal if `Q^T * Q = I`, meaning its columns are orthonormal.
+    /// Values are compared with a tolerance of `1e-5`, to allow for rounding errors.
+    /// Returns false if the matrix is not square.
     pub fn is_orthogonal(&self) -> bool {
-        unimplemented!()
+        if self.rows != self.cols {
+            return false;
+        }
+
+        let product = match self.get_transposed().multiply(self) {
+            Ok(mat) => mat,
+            Err(_) => return false,
+        };
+
+        let order = self.rows;
+        product.data.iter().enumerate().all(|(index, value)| {
+            let expected = if index / order == index % order {
+                T::ONE
+            } else {
+                T::ZERO
+            };
+            (*value - expected).abs() <= T::from_f64(ORTHOGONAL_TOLERANCE)
+        })
+    }
+
+    /// Calculate the determinant of a `2x2` matrix
+    ///
+    /// Uses the closed formula `ad - bc`.
+    /// Returns `MatrixError::ShapeMismatch` if the matrix is not `2x2`.
+    pub fn det_2x2(&self) -> Result<T, MatrixError> {
+        if self.rows != 2 || self.cols != 2 {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.shape(),
+                second_matrix_shape: String::from("2x2"),
+            });
+        }
+
+        let d = &self.data;
+        Ok(d[0] * d[3] - d[1] * d[2])
     }
 
-    pub fn det_2x2() {
-        unimplemented!()
+    /// Calculate the determinant of a `3x3` matrix
+    ///
+    /// Uses cofactor expansion along the first row.
+    /// Returns `MatrixError::ShapeMismatch` if the matrix is not `3x3`.
+    pub fn det_3x3(&self) -> Result<T, MatrixError> {
+        if self.rows != 3 || self.cols != 3 {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.shape(),
+                second_matrix_shape: String::from("3x3"),
+            });
+        }
+
+        let d = &self.data;
+        Ok(
+            d[0] * (d[4] * d[8] - d[5] * d[7]) - d[1] * (d[3] * d[8] - d[5] * d[6])
+                + d[2] * (d[3] * d[7] - d[4] * d[6]),
+        )
+    }
+}
+
+// Random constructors with a fixed range of `f32` values
+//
+// Use `with_rand_range` to create a random matrix of `f64` values.
+impl Matrix {
+    pub fn with_rand_bin(rows: usize, cols: usize) -> Self {
+        let data: Vec<f32> = rand::thread_rng()
+            .sample_iter(Standard)
+            .take(rows * cols)
+            .collect();
+        Matrix { data, rows, cols }
+    }
+
+    pub fn with_rand_0_to_10(rows: usize, cols: usize) -> Self {
+        let distribution: Uniform<f32> = Uniform::new_inclusive(0.0, 10.0);
+        let data: Vec<f32> = rand::thread_rng()
+            .sample_iter(distribution)
+            .take(rows * cols)
+            .collect();
+        Matrix { data, rows, cols }
     }
 
-    pub fn det_3x3() {
-        unimplemented!()
+    pub fn with_rand_neg10_to_10(rows: usize, cols: usize) -> Self {
+        let distribution: Uniform<f32> = Uniform::new_inclusive(-10.0, 10.0);
+        let data: Vec<f32> = rand::thread_rng()
+            .sample_iter(distribution)
+            .take(rows * cols)
+            .collect();
+        Matrix { data, rows, cols }
+    }
+}
+
+// Printing the matrix in the same layout as NumPy
+//
+// All values are right-aligned to the width of the widest value, and use the precision flag if given, `{:.3}`.
+// Matrices with more than `DISPLAY_THRESHOLD` values only print the first and last `DISPLAY_EDGE_ITEMS` rows and columns.
+impl<T: Float> Display for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.data.is_empty() {
+            return write!(f, "[]");
+        }
+
+        // Indices of the rows and columns to print, where `None` is the place of the ellipsis
+        let truncate = self.data.len() > DISPLAY_THRESHOLD;
+        let visible = |len: usize| -> Vec<Option<usize>> {
+            if truncate && len > 2 * DISPLAY_EDGE_ITEMS {
+                (0..DISPLAY_EDGE_ITEMS)
+                    .map(Some)
+                    .chain(std::iter::once(None))
+                    .chain((len - DISPLAY_EDGE_ITEMS..len).map(Some))
+                    .collect()
+            } else {
+                (0..len).map(Some).collect()
+            }
+        };
+        let rows = visible(self.rows);
+        let cols = visible(self.cols);
+
+        let precision = f.precision();
+        let lines: Vec<Option<Vec<Option<String>>>> = rows
+            .iter()
+            .map(|row| {
+                row.map(|row| {
+                    cols.iter()
+                        .map(|col| {
+                            col.map(|col| {
+                                let value = self.data[row * self.cols + col];
+                                match precision {
+                                    Some(precision) => format!("{value:.precision$}"),
+                                    None => format!("{value}"),
+                                }
+                            })
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        let width = lines
+            .iter()
+            .flatten()
+            .flatten()
+            .flatten()
+            .map(|value| value.len())
+            .max()
+            .unwrap_or(0);
+
+        for (index, line) in lines.iter().enumerate() {
+            if index == 0 {
+                write!(f, "[")?;
+            } else {
+                write!(f, "\n ")?;
+            }
+
+            match line {
+                Some(values) => {
+                    let values: Vec<String> = values
+                        .iter()
+                        .map(|value| match value {
+                            Some(value) => format!("{value:>width$}"),
+                            None => "...".to_string(),
+                        })
+                        .collect();
+                    write!(f, "[{}]", values.join(" "))?;
+                }
+                None => write!(f, "...")?,
+            }
+        }
+
+        write!(f, "]")
+    }
+}
+
+// Printing the matrix for debugging
+//
+// Prints the shape, followed by the same layout as `Display`, so large matrices are also truncated at the edges.
+impl<T: Float> std::fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Matrix({})", self.shape())?;
+        Display::fmt(self, f)
+    }
+}
+
+// Indexing with `matrix[(row, col)]` and `matrix[row]`
+//
+// Panics if the index is out of bounds, in the same way as indexing a slice.
+// Use `get`, `get_mut` or `get_row_as_slice` to get an `Option` instead.
+
+impl<T: Float> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.check_index(row, col);
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Float> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.check_index(row, col);
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Float> Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        self.get_row_as_slice(row).unwrap_or_else(|| {
+            panic!(
+                "row index out of bounds: the matrix has {} rows but the index is {row}",
+                self.rows
+            )
+        })
+    }
+}
+
+impl<T: Float> IndexMut<usize> for Matrix<T> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        let rows = self.rows;
+        self.get_row_mut(row).unwrap_or_else(|| {
+            panic!("row index out of bounds: the matrix has {rows} rows but the index is {row}")
+        })
+    }
+}
+
+// Operator overloading for element-wise matrix arithmetic
+//
+// Binary operators between two matrices panic if the shapes do not match, in the same way as indexing a slice out of bounds.
+// Use the fallible methods, such as `add_elementwise`, to handle a shape mismatch as an error.
+
+impl<T: Float> Add<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.add_elementwise(rhs)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl<T: Float> Add<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(mut self, rhs: &Matrix<T>) -> Matrix<T> {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Float> Add<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(mut self, rhs: Matrix<T>) -> Matrix<T> {
+        self += &rhs;
+        self
+    }
+}
+
+impl<T: Float> Sub<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.sub_elementwise(rhs)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl<T: Float> Sub<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(mut self, rhs: &Matrix<T>) -> Matrix<T> {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Float> Sub<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(mut self, rhs: Matrix<T>) -> Matrix<T> {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<T: Float> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Matrix<T> {
+        let mut matrix = self.clone();
+        matrix.scale_f(rhs);
+        matrix
+    }
+}
+
+impl<T: Float> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, rhs: T) -> Matrix<T> {
+        self.scale_f(rhs);
+        self
+    }
+}
+
+// Scaling with the scalar on the left, `2.0 * matrix`, must be implemented for each float type
+macro_rules! impl_scalar_mul {
+    ($float:ty) => {
+        impl Mul<&Matrix<$float>> for $float {
+            type Output = Matrix<$float>;
+
+            fn mul(self, rhs: &Matrix<$float>) -> Matrix<$float> {
+                rhs * self
+            }
+        }
+
+        impl Mul<Matrix<$float>> for $float {
+            type Output = Matrix<$float>;
+
+            fn mul(self, rhs: Matrix<$float>) -> Matrix<$float> {
+                rhs * self
+            }
+        }
+    };
+}
+
+impl_scalar_mul!(f32);
+impl_scalar_mul!(f64);
+
+// Division follows the rules of `f32` and `f64`, so dividing by zero gives infinity or NaN. Use `div_f` to get an error instead.
+impl<T: Float> Div<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn div(self, rhs: T) -> Matrix<T> {
+        let mut matrix = self.clone();
+        matrix /= rhs;
+        matrix
+    }
+}
+
+impl<T: Float> Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn div(mut self, rhs: T) -> Matrix<T> {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T: Float> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
+
+impl<T: Float> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        for item in self.data.iter_mut() {
+            *item = -*item;
+        }
+        self
+    }
+}
+
+impl<T: Float> AddAssign<&Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        self.zip_elementwise_in_place(rhs, |a, b| *a += b);
+    }
+}
+
+impl<T: Float> SubAssign<&Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        self.zip_elementwise_in_place(rhs, |a, b| *a -= b);
+    }
+}
+
+impl<T: Float> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.scale_f(rhs);
+    }
+}
+
+impl<T: Float> DivAssign<T> for Matrix<T> {
+    fn div_assign(&mut self, rhs: T) {
+        for item in self.data.iter_mut() {
+            *item /= rhs;
+        }
     }
 }
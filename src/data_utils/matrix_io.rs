@@ -0,0 +1,356 @@
+use crate::data_utils::float::Float;
+use crate::data_utils::matrix::Matrix;
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+/// Magic bytes at the start of a matrix in the binary format of this crate
+///
+/// The binary format is the magic bytes, the version (`u8`), the amount of bytes per value (`u8`),
+/// the rows and columns (`u64`), and then the values in row-major order. All numbers are little-endian.
+const BINARY_MAGIC: &[u8; 6] = b"RUSTML";
+
+/// Version of the binary format that is written by `Matrix::write_binary`
+const BINARY_VERSION: u8 = 1;
+
+/// Magic bytes at the start of a NumPy `.npy` file
+const NPY_MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// The header of a `.npy` file is padded with spaces, so the values start at a multiple of this amount of bytes.
+const NPY_ALIGNMENT: usize = 64;
+
+/// Custom Error type for reading and writing a `Matrix`
+#[derive(Debug)]
+pub enum MatrixIoError {
+    /// Reading or writing failed, for example when the file does not exist
+    Io(io::Error),
+
+    /// The bytes do not follow the expected format
+    InvalidFormat(String),
+
+    /// The values are stored as a type that can not be read into a matrix
+    UnsupportedDtype(String),
+}
+
+// For printing the error of reading or writing a matrix
+impl Display for MatrixIoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixIoError::Io(err) => {
+                write!(f, "Could not read or write the matrix: {err}")
+            }
+            MatrixIoError::InvalidFormat(val) => {
+                write!(f, "Invalid matrix format: {val}")
+            }
+            MatrixIoError::UnsupportedDtype(dtype) => {
+                write!(f, "Values of type '{dtype}' can not be read into a matrix")
+            }
+        }
+    }
+}
+
+// Making the MatrixIoError an Error, with the IO error as the source
+impl std::error::Error for MatrixIoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MatrixIoError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MatrixIoError {
+    fn from(err: io::Error) -> Self {
+        MatrixIoError::Io(err)
+    }
+}
+
+/// Read `count` little-endian values of `size` bytes each, and convert them to `T`
+///
+/// Only reads as many bytes as the reader has, so a corrupt shape does not allocate more memory than the data.
+fn read_values<T: Float, R: Read>(
+    reader: &mut R,
+    count: usize,
+    size: usize,
+) -> Result<Vec<T>, MatrixIoError> {
+    if size != 4 && size != 8 {
+        return Err(MatrixIoError::UnsupportedDtype(format!(
+            "float of {size} bytes"
+        )));
+    }
+
+    let len = count
+        .checked_mul(size)
+        .ok_or_else(|| MatrixIoError::InvalidFormat("the shape is too large".to_string()))?;
+    let mut bytes: Vec<u8> = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(MatrixIoError::InvalidFormat(format!(
+            "expected {len} bytes of values, but found {}",
+            bytes.len()
+        )));
+    }
+
+    let values = bytes.chunks_exact(size).map(|chunk| {
+        if size == 4 {
+            T::from_f64(f32::from_le_slice(chunk).to_f64())
+        } else {
+            T::from_f64(f64::from_le_slice(chunk))
+        }
+    });
+    Ok(values.collect())
+}
+
+/// Get the text after the given key in the header dictionary of a `.npy` file
+///
+/// The key can be quoted with single or double quotes, as the header is a Python dictionary.
+fn npy_header_field<'a>(header: &'a str, key: &str) -> Result<&'a str, MatrixIoError> {
+    [format!("'{key}':"), format!("\"{key}\":")]
+        .iter()
+        .find_map(|pattern| {
+            let start = header.find(pattern.as_str())?;
+            Some(header[start + pattern.len()..].trim_start())
+        })
+        .ok_or_else(|| {
+            MatrixIoError::InvalidFormat(format!("the .npy header has no '{key}' field"))
+        })
+}
+
+/// Parse the header dictionary of a `.npy` file
+///
+/// Returns the amount of bytes per value, if the values are in Fortran (column-major) order, and the shape.
+fn parse_npy_header(header: &str) -> Result<(usize, bool, Vec<usize>), MatrixIoError> {
+    let invalid = |field: &str| {
+        MatrixIoError::InvalidFormat(format!("could not parse '{field}' in the .npy header"))
+    };
+
+    // The type is a quoted string, such as '<f8'
+    let descr = npy_header_field(header, "descr")?;
+    let quote = descr.chars().next().ok_or_else(|| invalid("descr"))?;
+    let descr = descr[quote.len_utf8()..]
+        .split(quote)
+        .next()
+        .ok_or_else(|| invalid("descr"))?;
+    let size = match descr {
+        "<f4" => 4,
+        "<f8" => 8,
+        _ => return Err(MatrixIoError::UnsupportedDtype(descr.to_string())),
+    };
+
+    let fortran_order = npy_header_field(header, "fortran_order")?;
+    let fortran_order = if fortran_order.starts_with("True") {
+        true
+    } else if fortran_order.starts_with("False") {
+        false
+    } else {
+        return Err(invalid("fortran_order"));
+    };
+
+    // The shape is a tuple, such as (3, 4), (3,) or ()
+    let shape = npy_header_field(header, "shape")?;
+    let end = shape.find(')').ok_or_else(|| invalid("shape"))?;
+    let shape = shape
+        .get(1..end)
+        .ok_or_else(|| invalid("shape"))?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse::<usize>().map_err(|_| invalid("shape")))
+        .collect::<Result<Vec<usize>, MatrixIoError>>()?;
+
+    Ok((size, fortran_order, shape))
+}
+
+// Reading and writing a matrix in binary formats
+impl<T: Float> Matrix<T> {
+    /// Write the matrix in the binary format of this crate
+    ///
+    /// The values are written with all their precision, so `read_binary` gives back the exact same matrix.
+    /// Returns `MatrixIoError::Io` if writing fails.
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> Result<(), MatrixIoError> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(24 + self.data.len() * T::BYTES);
+        bytes.extend_from_slice(BINARY_MAGIC);
+        bytes.push(BINARY_VERSION);
+        bytes.push(T::BYTES as u8);
+        bytes.extend_from_slice(&(self.rows as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.cols as u64).to_le_bytes());
+        for value in &self.data {
+            value.extend_le_bytes(&mut bytes);
+        }
+
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Read a matrix that was written by `write_binary`
+    ///
+    /// Values written as another float type are converted to `T`, for example a `Matrix<f64>` can be read as a `Matrix<f32>`.
+    /// Returns `MatrixIoError::InvalidFormat` if the bytes are not a matrix in the binary format, or if there are too few values.
+    pub fn read_binary<R: Read>(mut reader: R) -> Result<Self, MatrixIoError> {
+        let mut header = [0u8; 24];
+        reader.read_exact(&mut header)?;
+
+        if &header[..6] != BINARY_MAGIC {
+            return Err(MatrixIoError::InvalidFormat(
+                "missing the magic bytes of a binary matrix".to_string(),
+            ));
+        }
+        if header[6] != BINARY_VERSION {
+            return Err(MatrixIoError::InvalidFormat(format!(
+                "unsupported binary matrix version {}",
+                header[6]
+            )));
+        }
+
+        let dimension = |bytes: &[u8]| {
+            let value = u64::from_le_bytes(bytes.try_into().expect("header field is 8 bytes"));
+            usize::try_from(value).map_err(|_| {
+                MatrixIoError::InvalidFormat(format!("the dimension {value} is too large"))
+            })
+        };
+        let rows = dimension(&header[8..16])?;
+        let cols = dimension(&header[16..24])?;
+        let count = rows
+            .checked_mul(cols)
+            .ok_or_else(|| MatrixIoError::InvalidFormat("the shape is too large".to_string()))?;
+
+        let data = read_values(&mut reader, count, header[7] as usize)?;
+        Ok(Matrix { data, rows, cols })
+    }
+
+    /// Save the matrix to a file in the binary format of this crate
+    ///
+    /// Creates the file, or overwrites it if it already exists. See `write_binary` for the format.
+    pub fn save_binary<P: AsRef<Path>>(&self, path: P) -> Result<(), MatrixIoError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_binary(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load a matrix from a file that was saved with `save_binary`
+    pub fn load_binary<P: AsRef<Path>>(path: P) -> Result<Self, MatrixIoError> {
+        Self::read_binary(BufReader::new(File::open(path)?))
+    }
+
+    /// Write the matrix in the NumPy `.npy` format
+    ///
+    /// Uses version 1.0 of the format, with little-endian values in C (row-major) order,
+    /// `<f4` for `f32` and `<f8` for `f64`. The result can be read with `numpy.load`.
+    /// Read more about the format: <https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html>
+    pub fn write_npy<W: Write>(&self, mut writer: W) -> Result<(), MatrixIoError> {
+        let descr = if T::BYTES == 4 { "<f4" } else { "<f8" };
+        let mut header = format!(
+            "{{'descr': '{descr}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            self.rows, self.cols
+        );
+
+        // Pad the header with spaces and end it with a newline, so the values are aligned
+        let unpadded = NPY_MAGIC.len() + 4 + header.len() + 1;
+        let padded = unpadded.div_ceil(NPY_ALIGNMENT) * NPY_ALIGNMENT;
+        header.push_str(&" ".repeat(padded - unpadded));
+        header.push('\n');
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(padded + self.data.len() * T::BYTES);
+        bytes.extend_from_slice(NPY_MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for value in &self.data {
+            value.extend_le_bytes(&mut bytes);
+        }
+
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Read a matrix from the NumPy `.npy` format
+    ///
+    /// Reads arrays of `<f4` or `<f8` values, and converts them to `T`. Arrays in Fortran order are converted to row-major order.
+    /// A 1-dimensional array of length `n` is read as a row vector `(1xn)`, and a 0-dimensional array as a `(1x1)` matrix.
+    /// Returns `MatrixIoError::UnsupportedDtype` for other value types,
+    /// and `MatrixIoError::InvalidFormat` if the header can not be parsed or the array has more than 2 dimensions.
+    pub fn read_npy<R: Read>(mut reader: R) -> Result<Self, MatrixIoError> {
+        let mut prefix = [0u8; 8];
+        reader.read_exact(&mut prefix)?;
+        if &prefix[..6] != NPY_MAGIC {
+            return Err(MatrixIoError::InvalidFormat(
+                "missing the magic string of a .npy file".to_string(),
+            ));
+        }
+
+        // Version 1 stores the length of the header as a u16, and later versions as a u32
+        let header_len = match prefix[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            major => {
+                return Err(MatrixIoError::InvalidFormat(format!(
+                    "unsupported .npy version {major}.{}",
+                    prefix[7]
+                )))
+            }
+        };
+
+        let mut header: Vec<u8> = Vec::new();
+        (&mut reader)
+            .take(header_len as u64)
+            .read_to_end(&mut header)?;
+        let header = String::from_utf8(header).map_err(|_| {
+            MatrixIoError::InvalidFormat("the .npy header is not valid text".to_string())
+        })?;
+        let (size, fortran_order, shape) = parse_npy_header(&header)?;
+
+        let (rows, cols) = match shape[..] {
+            [] => (1, 1),
+            [len] => (1, len),
+            [rows, cols] => (rows, cols),
+            _ => {
+                return Err(MatrixIoError::InvalidFormat(format!(
+                "only arrays with 1 or 2 dimensions can be read as a matrix, found shape {shape:?}"
+            )))
+            }
+        };
+        let count = rows
+            .checked_mul(cols)
+            .ok_or_else(|| MatrixIoError::InvalidFormat("the shape is too large".to_string()))?;
+        let data = read_values(&mut reader, count, size)?;
+
+        if fortran_order {
+            let mut matrix = Matrix {
+                data,
+                rows: cols,
+                cols: rows,
+            };
+            matrix.transpose();
+            return Ok(matrix);
+        }
+
+        Ok(Matrix { data, rows, cols })
+    }
+
+    /// Save the matrix to a NumPy `.npy` file
+    ///
+    /// Creates the file, or overwrites it if it already exists. See `write_npy` for the format.
+    pub fn save_npy<P: AsRef<Path>>(&self, path: P) -> Result<(), MatrixIoError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_npy(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load a matrix from a NumPy `.npy` file, such as a file saved with `numpy.save`
+    pub fn load_npy<P: AsRef<Path>>(path: P) -> Result<Self, MatrixIoError> {
+        Self::read_npy(BufReader::new(File::open(path)?))
+    }
+}
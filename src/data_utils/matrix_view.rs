@@ -0,0 +1,624 @@
+use crate::data_utils::float::Float;
+use crate::data_utils::matrix::{check_region, Axis, Matrix, MatrixError};
+use std::ops::{AddAssign, Index, IndexMut, Range, SubAssign};
+
+/// Borrowed view into a rectangular region of matrix data
+///
+/// A view does not copy any values. The value at `(row, col)` is found at
+/// `row * row_stride + col * col_stride` in the borrowed data,
+/// so a view can describe a submatrix, a transposed matrix or every n-th row without moving data.
+/// Views are cheap to copy, and operations that produce new values return an owned `Matrix`.
+#[derive(Clone, Copy)]
+pub struct MatrixView<'a, T = f32> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Mutable borrowed view into a rectangular region of matrix data
+///
+/// Works like `MatrixView`, but values written through the view are written to the borrowed data.
+pub struct MatrixViewMut<'a, T = f32> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Check that a view with the given shape and strides only reads values inside the data
+fn check_strides(
+    len: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+) -> Result<(), MatrixError> {
+    // Empty views never read any data
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+
+    // Offset of the last value of the view, if it does not overflow
+    let last = (rows - 1)
+        .checked_mul(row_stride)
+        .zip((cols - 1).checked_mul(col_stride))
+        .and_then(|(row_offset, col_offset)| row_offset.checked_add(col_offset));
+
+    match last {
+        Some(last) if last < len => Ok(()),
+        _ => Err(MatrixError::IllegalRange(format!(
+            "A view with shape {rows}x{cols} and strides ({row_stride}, {col_stride}) does not fit in {len} values"
+        ))),
+    }
+}
+
+// Creating views from a matrix
+impl<T: Float> Matrix<T> {
+    /// Get a view of the whole matrix
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: &self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.cols,
+            col_stride: 1,
+        }
+    }
+
+    /// Get a mutable view of the whole matrix
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            data: &mut self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.cols,
+            col_stride: 1,
+        }
+    }
+
+    /// Get a view of a rectangular region of the matrix, without copying the values
+    ///
+    /// Ranges start from 0 and are not inclusive of the end value.
+    /// Returns `MatrixError::IllegalRange` if the ranges are empty or bigger than the shape of the `Matrix`
+    pub fn view(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        self.as_view().view(rows, cols)
+    }
+
+    /// Get a mutable view of a rectangular region of the matrix, without copying the values
+    ///
+    /// Ranges start from 0 and are not inclusive of the end value.
+    /// Returns `MatrixError::IllegalRange` if the ranges are empty or bigger than the shape of the `Matrix`
+    pub fn view_mut(
+        &mut self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<MatrixViewMut<'_, T>, MatrixError> {
+        check_region(&rows, &cols, self.rows, self.cols)?;
+        let start = rows.start * self.cols + cols.start;
+        Ok(MatrixViewMut {
+            data: &mut self.data[start..],
+            rows: rows.len(),
+            cols: cols.len(),
+            row_stride: self.cols,
+            col_stride: 1,
+        })
+    }
+}
+
+// Creating a view of a whole matrix
+impl<'a, T: Float> From<&'a Matrix<T>> for MatrixView<'a, T> {
+    fn from(matrix: &'a Matrix<T>) -> Self {
+        matrix.as_view()
+    }
+}
+
+impl<'a, T: Float> MatrixView<'a, T> {
+    /// Create a view over the given data with the given shape and strides
+    ///
+    /// The value at `(row, col)` is `data[row * row_stride + col * col_stride]`.
+    /// Returns `MatrixError::IllegalRange` if the view would read outside of the data.
+    pub fn new(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Result<Self, MatrixError> {
+        check_strides(data.len(), rows, cols, row_stride, col_stride)?;
+        Ok(MatrixView {
+            data,
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+        })
+    }
+
+    /// Get the amount of rows in the view
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the amount of columns in the view
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the distance in the borrowed data between two neighbouring rows
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    /// Get the distance in the borrowed data between two neighbouring columns
+    pub fn col_stride(&self) -> usize {
+        self.col_stride
+    }
+
+    /// Get the shape of the view.
+    ///
+    /// Format of the string is "ROWSxCOLUMNS", same as `Matrix::shape`.
+    pub fn shape(&self) -> String {
+        format!("{}x{}", self.rows, self.cols)
+    }
+
+    /// Check if the values of each row are next to each other in the borrowed data
+    pub fn has_contiguous_rows(&self) -> bool {
+        self.col_stride == 1 || self.cols <= 1
+    }
+
+    /// Get the value at the given row and column
+    ///
+    /// Returns `None` if the index is outside of the view
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(&self.data[row * self.row_stride + col * self.col_stride])
+    }
+
+    /// Get a copy of the row at the given index
+    pub fn get_row(&self, index: usize) -> Option<Vec<T>> {
+        if index >= self.rows {
+            return None;
+        }
+        Some((0..self.cols).map(|col| self[(index, col)]).collect())
+    }
+
+    /// Get the row at the given index as a slice of the borrowed data
+    ///
+    /// Returns `None` if the index is outside of the view, or if the values of the row are not contiguous.
+    pub fn get_row_as_slice(&self, index: usize) -> Option<&'a [T]> {
+        if index >= self.rows || !self.has_contiguous_rows() {
+            return None;
+        }
+        let start = index * self.row_stride;
+        Some(&self.data[start..start + self.cols])
+    }
+
+    /// Get a copy of the column at the given index
+    pub fn get_col(&self, index: usize) -> Option<Vec<T>> {
+        if index >= self.cols {
+            return None;
+        }
+        Some((0..self.rows).map(|row| self[(row, index)]).collect())
+    }
+
+    /// Iterate over the values of the view, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        (0..view.rows).flat_map(move |row| {
+            (0..view.cols).map(move |col| &view.data[row * view.row_stride + col * view.col_stride])
+        })
+    }
+
+    /// Get a view of a rectangular region of this view
+    ///
+    /// Ranges start from 0 and are not inclusive of the end value.
+    /// Returns `MatrixError::IllegalRange` if the ranges are empty or bigger than the shape of the view
+    pub fn view(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        check_region(&rows, &cols, self.rows, self.cols)?;
+        let start = rows.start * self.row_stride + cols.start * self.col_stride;
+        Ok(MatrixView {
+            data: &self.data[start..],
+            rows: rows.len(),
+            cols: cols.len(),
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        })
+    }
+
+    /// Get the transposed view, without copying the values
+    ///
+    /// Swaps rows with columns by swapping the strides.
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    /// Copy the values of the view into a new matrix
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            data: self.iter().copied().collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Multiply two views
+    ///
+    /// Same condition as `Matrix::multiply`: the columns of this view must equal the rows of the given view.
+    /// Returns `MatrixError::MatrixMultiply` if this condition is not met
+    pub fn multiply(&self, view: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != view.rows {
+            return Err(MatrixError::MatrixMultiply);
+        }
+
+        let mut matrix = Matrix::new_typed(self.rows, view.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let value = self[(i, k)];
+                for j in 0..view.cols {
+                    matrix.data[i * view.cols + j] += value * view[(k, j)];
+                }
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    /// Add two views of the same shape, value by value
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if the views do not have the same shape
+    pub fn add_elementwise(&self, view: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_elementwise(view, |a, b| a + b)
+    }
+
+    /// Subtract the given view from this view, value by value
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if the views do not have the same shape
+    pub fn sub_elementwise(&self, view: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_elementwise(view, |a, b| a - b)
+    }
+
+    /// Multiply two views of the same shape, value by value
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if the views do not have the same shape
+    pub fn mul_elementwise(&self, view: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_elementwise(view, |a, b| a * b)
+    }
+
+    /// Divide this view by the given view, value by value
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if the views do not have the same shape,
+    /// and `MatrixError::DivideByZero` if the given view contains a zero
+    pub fn div_elementwise(&self, view: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_same_shape(view)?;
+        if view.iter().any(|x| *x == T::ZERO) {
+            return Err(MatrixError::DivideByZero);
+        }
+        self.zip_elementwise(view, |a, b| a / b)
+    }
+
+    /// Sum all the values in the view
+    pub fn sum(&self) -> T {
+        self.iter().sum()
+    }
+
+    /// Calculate the mean of all the values in the view
+    ///
+    /// Returns `NaN` if the view has no values.
+    pub fn mean(&self) -> T {
+        self.sum() / T::from_usize(self.rows * self.cols)
+    }
+
+    /// Sum the values along the given axis
+    ///
+    /// Same as `Matrix::sum_axis`.
+    pub fn sum_axis(&self, axis: Axis) -> Matrix<T> {
+        match axis {
+            Axis::Rows => Matrix {
+                data: (0..self.cols)
+                    .map(|col| (0..self.rows).map(|row| self[(row, col)]).sum())
+                    .collect(),
+                rows: 1,
+                cols: self.cols,
+            },
+            Axis::Cols => Matrix {
+                data: (0..self.rows)
+                    .map(|row| (0..self.cols).map(|col| self[(row, col)]).sum())
+                    .collect(),
+                rows: self.rows,
+                cols: 1,
+            },
+        }
+    }
+
+    /// Calculate the mean of the values along the given axis
+    ///
+    /// Same as `Matrix::mean_axis`.
+    pub fn mean_axis(&self, axis: Axis) -> Matrix<T> {
+        let count = T::from_usize(match axis {
+            Axis::Rows => self.rows,
+            Axis::Cols => self.cols,
+        });
+        let mut mean = self.sum_axis(axis);
+        for item in mean.data.iter_mut() {
+            *item /= count;
+        }
+        mean
+    }
+
+    /// Check that the given view has the same shape as this view
+    fn check_same_shape(&self, view: &MatrixView<'_, T>) -> Result<(), MatrixError> {
+        if self.rows != view.rows || self.cols != view.cols {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: self.shape(),
+                second_matrix_shape: view.shape(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Combine two views of the same shape value by value into a new matrix
+    fn zip_elementwise<F>(
+        &self,
+        view: &MatrixView<'_, T>,
+        operation: F,
+    ) -> Result<Matrix<T>, MatrixError>
+    where
+        F: Fn(T, T) -> T,
+    {
+        self.check_same_shape(view)?;
+        Ok(Matrix {
+            data: self
+                .iter()
+                .zip(view.iter())
+                .map(|(a, b)| operation(*a, *b))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+}
+
+impl<'a, T: Float> MatrixViewMut<'a, T> {
+    /// Create a mutable view over the given data with the given shape and strides
+    ///
+    /// The value at `(row, col)` is `data[row * row_stride + col * col_stride]`.
+    /// Strides that make two positions share the same value are allowed, writes then overwrite each other.
+    /// Returns `MatrixError::IllegalRange` if the view would reach outside of the data.
+    pub fn new(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Result<Self, MatrixError> {
+        check_strides(data.len(), rows, cols, row_stride, col_stride)?;
+        Ok(MatrixViewMut {
+            data,
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+        })
+    }
+
+    /// Get the amount of rows in the view
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the amount of columns in the view
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the shape of the view.
+    ///
+    /// Format of the string is "ROWSxCOLUMNS", same as `Matrix::shape`.
+    pub fn shape(&self) -> String {
+        format!("{}x{}", self.rows, self.cols)
+    }
+
+    /// Borrow the mutable view as an immutable view
+    ///
+    /// Gives access to all the read operations of `MatrixView`.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    /// Get the value at the given row and column
+    ///
+    /// Returns `None` if the index is outside of the view
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(&self.data[row * self.row_stride + col * self.col_stride])
+    }
+
+    /// Get a mutable reference to the value at the given row and column
+    ///
+    /// Returns `None` if the index is outside of the view
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(&mut self.data[row * self.row_stride + col * self.col_stride])
+    }
+
+    /// Get a mutable view of a rectangular region of this view
+    ///
+    /// Ranges start from 0 and are not inclusive of the end value.
+    /// Returns `MatrixError::IllegalRange` if the ranges are empty or bigger than the shape of the view
+    pub fn view_mut(
+        &mut self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<MatrixViewMut<'_, T>, MatrixError> {
+        check_region(&rows, &cols, self.rows, self.cols)?;
+        let start = rows.start * self.row_stride + cols.start * self.col_stride;
+        Ok(MatrixViewMut {
+            data: &mut self.data[start..],
+            rows: rows.len(),
+            cols: cols.len(),
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        })
+    }
+
+    /// Copy the values of the view into a new matrix
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.as_view().to_matrix()
+    }
+
+    /// Set all the values of the view to the given value
+    pub fn fill(&mut self, value: T) {
+        self.for_each_mut(|item| *item = value);
+    }
+
+    /// Copy the values of the given view into this view
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if the views do not have the same shape
+    pub fn copy_from(&mut self, view: &MatrixView<'_, T>) -> Result<(), MatrixError> {
+        self.zip_in_place(view, |item, value| *item = value)
+    }
+
+    /// Add a value to each of the values in the view
+    pub fn add_f(&mut self, numb: T) {
+        self.for_each_mut(|item| *item += numb);
+    }
+
+    /// Subtract a value from each of the values in the view
+    pub fn sub_f(&mut self, numb: T) {
+        self.for_each_mut(|item| *item -= numb);
+    }
+
+    /// Scale each of the values in the view by the given value
+    pub fn scale_f(&mut self, numb: T) {
+        self.for_each_mut(|item| *item *= numb);
+    }
+
+    /// Divide each of the values in the view by the given value
+    ///
+    /// Returns `MatrixError::DivideByZero` if the given value is zero, the view is then left unchanged
+    pub fn div_f(&mut self, numb: T) -> Result<(), MatrixError> {
+        if numb == T::ZERO {
+            return Err(MatrixError::DivideByZero);
+        }
+        self.for_each_mut(|item| *item /= numb);
+        Ok(())
+    }
+
+    /// Apply the operation to each of the values in the view
+    fn for_each_mut<F>(&mut self, operation: F)
+    where
+        F: Fn(&mut T),
+    {
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                operation(&mut self.data[row * self.row_stride + col * self.col_stride]);
+            }
+        }
+    }
+
+    /// Combine the values of the given view of the same shape into this view
+    fn zip_in_place<F>(&mut self, view: &MatrixView<'_, T>, operation: F) -> Result<(), MatrixError>
+    where
+        F: Fn(&mut T, T),
+    {
+        self.as_view().check_same_shape(view)?;
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                operation(
+                    &mut self.data[row * self.row_stride + col * self.col_stride],
+                    view[(row, col)],
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+// Indexing a view with (row, col)
+impl<T: Float> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the view has shape {} but the index is ({row}, {col})",
+                self.shape()
+            ),
+        }
+    }
+}
+
+impl<T: Float> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the view has shape {} but the index is ({row}, {col})",
+                self.shape()
+            ),
+        }
+    }
+}
+
+impl<T: Float> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        // The view is borrowed by `get_mut`, so the shape is copied for the panic message
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(row, col) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the view has shape {rows}x{cols} but the index is ({row}, {col})"
+            ),
+        }
+    }
+}
+
+// Adding and subtracting a view in place, panics if the shapes does not match
+impl<T: Float> AddAssign<&MatrixView<'_, T>> for MatrixViewMut<'_, T> {
+    fn add_assign(&mut self, rhs: &MatrixView<'_, T>) {
+        if let Err(error) = self.zip_in_place(rhs, |item, value| *item += value) {
+            panic!("{error}");
+        }
+    }
+}
+
+impl<T: Float> SubAssign<&MatrixView<'_, T>> for MatrixViewMut<'_, T> {
+    fn sub_assign(&mut self, rhs: &MatrixView<'_, T>) {
+        if let Err(error) = self.zip_in_place(rhs, |item, value| *item -= value) {
+            panic!("{error}");
+        }
+    }
+}
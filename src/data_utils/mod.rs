@@ -1,8 +1,24 @@
 //! This module provides the core functionality for working with data structures.
 //! It includes the following modules:
+//! - `convolution`: Provides 2D convolution and pooling of matrices, for image filters and convolutional layers.
+//! - `csv`: Reads and writes the records of CSV files with quoted fields, following RFC 4180.
 //! - `datacolumn`: Handles operations related to data columns.
 //! - `dataframe`: Implements a data frame structure for data manipulation.
+//! - `decomposition`: Provides matrix decompositions, such as LU, QR, Cholesky, eigendecomposition and SVD.
+//! - `float`: Defines the `Float` trait for the element types of a matrix, `f32` and `f64`.
 //! - `matrix`: Provides functionality for matrix operations.
+//! - `matrix_io`: Reads and writes matrices in a binary format and the NumPy `.npy` format.
+//! - `matrix_view`: Provides borrowed views into the values of a matrix.
+//! - `sparse_matrix`: Provides a sparse matrix that only stores the values that are not zero.
+//! - `tensor`: Provides an N-dimensional tensor for batches of images and sequences.
+pub mod convolution;
+pub mod csv;
 pub mod datacolumn;
 pub mod dataframe;
+pub mod decomposition;
+pub mod float;
 pub mod matrix;
+pub mod matrix_io;
+pub mod matrix_view;
+pub mod sparse_matrix;
+pub mod tensor;
@@ -0,0 +1,390 @@
+use crate::data_utils::float::Float;
+use crate::data_utils::matrix::{Matrix, MatrixError};
+
+/// Storage layout of a `SparseMatrix`
+///
+/// Both layouts store the non-zero values in compressed groups.
+/// Read more: <https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseFormat {
+    /// Compressed sparse row, the values are grouped by row. Fast to multiply with a dense matrix on the right.
+    Csr,
+
+    /// Compressed sparse column, the values are grouped by column. Fast to multiply with a dense matrix on the left.
+    Csc,
+}
+
+/// Sparse matrix that only stores the values that are not zero
+///
+/// Uses much less memory than a `Matrix` when most values are zero, such as bag-of-words features or one-hot encoded data,
+/// and multiplication only does work for the stored values.
+/// The values are stored in compressed groups of rows or columns, see `SparseFormat`.
+/// Two sparse matrices are only equal if they have the same layout, compare `to_dense` to ignore the layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<T = f32> {
+    format: SparseFormat,
+    rows: usize,
+    cols: usize,
+
+    /// Start of each group in `indices` and `values`, with the total amount of values as the last item
+    indptr: Vec<usize>,
+
+    /// Column (CSR) or row (CSC) of each stored value, sorted within each group
+    indices: Vec<usize>,
+
+    /// Stored values
+    values: Vec<T>,
+}
+
+/// Change the grouping of compressed data, from rows to columns or from columns to rows
+///
+/// `minor_len` is the amount of groups in the result. The result is sorted within each group,
+/// as the groups of the input are visited in order.
+fn transpose_compressed<T: Float>(
+    minor_len: usize,
+    indptr: &[usize],
+    indices: &[usize],
+    values: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    // Count the values of each new group, and turn the counts into start positions
+    let mut new_indptr = vec![0; minor_len + 1];
+    for index in indices {
+        new_indptr[index + 1] += 1;
+    }
+    for i in 0..minor_len {
+        new_indptr[i + 1] += new_indptr[i];
+    }
+
+    let mut next = new_indptr.clone();
+    let mut new_indices = vec![0; indices.len()];
+    let mut new_values = vec![T::ZERO; values.len()];
+    for major in 0..indptr.len() - 1 {
+        for position in indptr[major]..indptr[major + 1] {
+            let minor = indices[position];
+            new_indices[next[minor]] = major;
+            new_values[next[minor]] = values[position];
+            next[minor] += 1;
+        }
+    }
+
+    (new_indptr, new_indices, new_values)
+}
+
+impl<T: Float> SparseMatrix<T> {
+    /// Create a new sparse matrix where all values are zero
+    pub fn new(rows: usize, cols: usize, format: SparseFormat) -> Self {
+        let groups = match format {
+            SparseFormat::Csr => rows,
+            SparseFormat::Csc => cols,
+        };
+
+        SparseMatrix {
+            format,
+            rows,
+            cols,
+            indptr: vec![0; groups + 1],
+            indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Create a sparse matrix from `(row, col, value)` triplets
+    ///
+    /// The triplets can be given in any order, and the values of duplicate positions are summed.
+    /// Returns `MatrixError::IllegalRange` if a position is outside the `(rows x cols)` shape.
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: &[(usize, usize, T)],
+        format: SparseFormat,
+    ) -> Result<Self, MatrixError> {
+        if let Some((row, col, _)) = triplets
+            .iter()
+            .find(|(row, col, _)| *row >= rows || *col >= cols)
+        {
+            return Err(MatrixError::IllegalRange(format!(
+                "The position ({row}, {col}) is outside the shape {rows}x{cols}"
+            )));
+        }
+
+        // Sort by group and then by the index within the group
+        let mut entries: Vec<(usize, usize, T)> = triplets
+            .iter()
+            .map(|(row, col, value)| match format {
+                SparseFormat::Csr => (*row, *col, *value),
+                SparseFormat::Csc => (*col, *row, *value),
+            })
+            .collect();
+        entries.sort_by_key(|(major, minor, _)| (*major, *minor));
+
+        let mut matrix = Self::new(rows, cols, format);
+        let mut previous: Option<(usize, usize)> = None;
+        for (major, minor, value) in entries {
+            if previous == Some((major, minor)) {
+                *matrix.values.last_mut().expect("duplicate has a value") += value;
+            } else {
+                matrix.indices.push(minor);
+                matrix.values.push(value);
+                matrix.indptr[major + 1] += 1;
+            }
+            previous = Some((major, minor));
+        }
+
+        // Turn the amount of values in each group into start positions
+        for i in 0..matrix.indptr.len() - 1 {
+            matrix.indptr[i + 1] += matrix.indptr[i];
+        }
+
+        Ok(matrix)
+    }
+
+    /// Create a sparse matrix with the values of a dense matrix that are not zero
+    pub fn from_dense(mat: &Matrix<T>, format: SparseFormat) -> Self {
+        let mut matrix = Self::new(mat.rows, mat.cols, SparseFormat::Csr);
+        for row in 0..mat.rows {
+            for col in 0..mat.cols {
+                let value = mat.data[row * mat.cols + col];
+                if value != T::ZERO {
+                    matrix.indices.push(col);
+                    matrix.values.push(value);
+                }
+            }
+            matrix.indptr[row + 1] = matrix.indices.len();
+        }
+
+        matrix.to_format(format)
+    }
+
+    /// Convert the sparse matrix to a dense `Matrix`
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut result = Matrix::new_typed(self.rows, self.cols);
+        for (row, col, value) in self.iter() {
+            result.data[row * self.cols + col] = value;
+        }
+        result
+    }
+
+    /// Get a copy of the sparse matrix in the given storage layout
+    ///
+    /// Returns a plain copy if the matrix already has the layout.
+    pub fn to_format(&self, format: SparseFormat) -> Self {
+        if self.format == format {
+            return self.clone();
+        }
+
+        let minor_len = match self.format {
+            SparseFormat::Csr => self.cols,
+            SparseFormat::Csc => self.rows,
+        };
+        let (indptr, indices, values) =
+            transpose_compressed(minor_len, &self.indptr, &self.indices, &self.values);
+
+        SparseMatrix {
+            format,
+            rows: self.rows,
+            cols: self.cols,
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    /// Get a copy of the sparse matrix in the compressed sparse row layout
+    pub fn to_csr(&self) -> Self {
+        self.to_format(SparseFormat::Csr)
+    }
+
+    /// Get a copy of the sparse matrix in the compressed sparse column layout
+    pub fn to_csc(&self) -> Self {
+        self.to_format(SparseFormat::Csc)
+    }
+
+    /// Get the amount of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the amount of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the shape of the sparse matrix.
+    ///
+    /// Format of the string is "ROWSxCOLUMNS", the same as `Matrix::shape`.
+    pub fn shape(&self) -> String {
+        format!("{}x{}", self.rows, self.cols)
+    }
+
+    /// Get the storage layout of the sparse matrix
+    pub fn format(&self) -> SparseFormat {
+        self.format
+    }
+
+    /// Get the amount of stored values
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Get the part of the values that are stored, between `0.0` and `1.0`
+    pub fn density(&self) -> f64 {
+        if self.rows * self.cols == 0 {
+            return 0.0;
+        }
+        self.nnz() as f64 / (self.rows * self.cols) as f64
+    }
+
+    /// Get the value at the given position
+    ///
+    /// Returns zero for positions without a stored value, and None if the position is outside the matrix.
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let (major, minor) = match self.format {
+            SparseFormat::Csr => (row, col),
+            SparseFormat::Csc => (col, row),
+        };
+        let group = self.indptr[major]..self.indptr[major + 1];
+        match self.indices[group.clone()].binary_search(&minor) {
+            Ok(position) => Some(self.values[group.start + position]),
+            Err(_) => Some(T::ZERO),
+        }
+    }
+
+    /// Iterate over the stored values as `(row, col, value)`
+    ///
+    /// The values are given by row for CSR, and by column for CSC.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.indptr.len() - 1).flat_map(move |major| {
+            (self.indptr[major]..self.indptr[major + 1]).map(move |position| {
+                let minor = self.indices[position];
+                match self.format {
+                    SparseFormat::Csr => (major, minor, self.values[position]),
+                    SparseFormat::Csc => (minor, major, self.values[position]),
+                }
+            })
+        })
+    }
+
+    /// Transpose the sparse matrix
+    ///
+    /// A CSR matrix has the same stored data as the CSC layout of its transpose,
+    /// so this only swaps the shape and the layout, and does not move any values.
+    pub fn transpose(&mut self) {
+        std::mem::swap(&mut self.rows, &mut self.cols);
+        self.format = match self.format {
+            SparseFormat::Csr => SparseFormat::Csc,
+            SparseFormat::Csc => SparseFormat::Csr,
+        };
+    }
+
+    /// Get a transposed copy of the sparse matrix
+    ///
+    /// The copy has the other layout, see `transpose`. Use `to_format` on the result to keep the original layout.
+    pub fn get_transposed(&self) -> Self {
+        let mut result = self.clone();
+        result.transpose();
+        result
+    }
+
+    /// Scale all values in the sparse matrix by a given scalar (`T`)
+    ///
+    /// Mutates the matrix and makes the change.
+    pub fn scale_f(&mut self, numb: T) {
+        for value in self.values.iter_mut() {
+            *value *= numb;
+        }
+    }
+
+    /// Divide all values in the sparse matrix by a given scalar (`T`)
+    ///
+    /// Mutates the matrix and makes the change.
+    /// Returns `MatrixError::DivideByZero` if the scalar is zero, and leaves the matrix unchanged.
+    pub fn div_f(&mut self, numb: T) -> Result<(), MatrixError> {
+        if numb == T::ZERO {
+            return Err(MatrixError::DivideByZero);
+        }
+
+        for value in self.values.iter_mut() {
+            *value /= numb;
+        }
+        Ok(())
+    }
+
+    /// Multiply the sparse matrix with a dense matrix, `self * mat`
+    ///
+    /// Only the stored values are multiplied, so the cost is proportional to `nnz * mat.cols`.
+    /// Returns `MatrixError::MatrixMultiply` if the columns of the sparse matrix do not equal the rows of `mat`.
+    pub fn multiply_dense(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != mat.rows {
+            return Err(MatrixError::MatrixMultiply);
+        }
+
+        // Each stored value adds a scaled row of `mat` to a row of the result
+        let mut result = Matrix::new_typed(self.rows, mat.cols);
+        for (row, inner, value) in self.iter() {
+            let source = &mat.data[inner * mat.cols..(inner + 1) * mat.cols];
+            let target = &mut result.data[row * mat.cols..(row + 1) * mat.cols];
+            for (target, source) in target.iter_mut().zip(source) {
+                *target += value * *source;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Multiply a dense matrix with the sparse matrix, `mat * self`
+    ///
+    /// Only the stored values are multiplied, so the cost is proportional to `nnz * mat.rows`.
+    /// A CSC matrix computes each row of the result from a single row of `mat`, which is faster than the CSR layout.
+    /// Returns `MatrixError::MatrixMultiply` if the columns of `mat` do not equal the rows of the sparse matrix.
+    pub fn dense_multiply(&self, mat: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if mat.cols != self.rows {
+            return Err(MatrixError::MatrixMultiply);
+        }
+
+        let mut result = Matrix::new_typed(mat.rows, self.cols);
+        match self.format {
+            SparseFormat::Csc => {
+                // Each value of the result is the dot product of a row of `mat` and a stored column
+                let source_rows = mat.data.chunks_exact(mat.cols.max(1));
+                let target_rows = result.data.chunks_exact_mut(self.cols.max(1));
+                for (source, target) in source_rows.zip(target_rows) {
+                    for (col, target) in target.iter_mut().enumerate() {
+                        let group = self.indptr[col]..self.indptr[col + 1];
+                        *target = self.indices[group.clone()]
+                            .iter()
+                            .zip(&self.values[group])
+                            .map(|(inner, value)| source[*inner] * *value)
+                            .sum();
+                    }
+                }
+            }
+            SparseFormat::Csr => {
+                // Each stored value adds a scaled column of `mat` to a column of the result
+                for (inner, col, value) in self.iter() {
+                    for row in 0..mat.rows {
+                        let product = mat.data[row * mat.cols + inner] * value;
+                        result.data[row * self.cols + col] += product;
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<T: Float> From<&Matrix<T>> for SparseMatrix<T> {
+    fn from(mat: &Matrix<T>) -> Self {
+        SparseMatrix::from_dense(mat, SparseFormat::Csr)
+    }
+}
+
+impl<T: Float> From<&SparseMatrix<T>> for Matrix<T> {
+    fn from(mat: &SparseMatrix<T>) -> Self {
+        mat.to_dense()
+    }
+}
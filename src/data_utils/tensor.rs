@@ -0,0 +1,553 @@
+use crate::data_utils::float::Float;
+use crate::data_utils::matrix::{Matrix, MatrixError};
+use std::ops::{Index, IndexMut, Range};
+
+/// N-dimensional array of values
+///
+/// A tensor has a shape with any amount of dimensions, such as `(N, C, H, W)` for a batch of images,
+/// or `(N, T, F)` for a batch of sequences. The value at an index is found at the sum of `index[i] * strides[i]` in the data,
+/// so `permute` can reorder the axes without moving any values.
+/// Use `Matrix` for two dimensions, the tensor converts to and from a matrix.
+#[derive(Debug, Clone)]
+pub struct Tensor<T = f32> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+/// Format a shape in the same way as `Matrix::shape`, such as "2x3x4"
+fn format_shape(shape: &[usize]) -> String {
+    shape
+        .iter()
+        .map(|dim| dim.to_string())
+        .collect::<Vec<String>>()
+        .join("x")
+}
+
+/// Strides of a row-major (C order) layout of the shape, where the last axis is next to each other
+fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for axis in (0..shape.len().saturating_sub(1)).rev() {
+        strides[axis] = strides[axis + 1] * shape[axis + 1];
+    }
+    strides
+}
+
+/// Find the shape that two shapes are broadcast to
+///
+/// The shapes are aligned at the last axis, and each pair of dimensions must be equal, or one of them must be 1.
+/// This is the same as broadcasting in NumPy.
+fn broadcast_shapes(first: &[usize], second: &[usize]) -> Result<Vec<usize>, MatrixError> {
+    let ndim = first.len().max(second.len());
+    let dim = |shape: &[usize], axis: usize| {
+        (axis + shape.len())
+            .checked_sub(ndim)
+            .map_or(1, |axis| shape[axis])
+    };
+
+    (0..ndim)
+        .map(|axis| match (dim(first, axis), dim(second, axis)) {
+            (a, b) if a == b || b == 1 => Ok(a),
+            (1, b) => Ok(b),
+            _ => Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: format_shape(first),
+                second_matrix_shape: format_shape(second),
+            }),
+        })
+        .collect()
+}
+
+/// Strides that read a layout as if it had the broadcast shape
+///
+/// Missing leading axes and axes of length 1 get the stride 0, so the same values are read again along them.
+fn broadcast_strides(shape: &[usize], strides: &[usize], broadcast_shape: &[usize]) -> Vec<usize> {
+    let missing = broadcast_shape.len() - shape.len();
+    (0..broadcast_shape.len())
+        .map(|axis| {
+            if axis < missing || shape[axis - missing] == 1 {
+                0
+            } else {
+                strides[axis - missing]
+            }
+        })
+        .collect()
+}
+
+/// Iterator over the positions in the data of a strided layout, in row-major order of the shape
+struct StridedOffsets {
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    index: Vec<usize>,
+    offset: usize,
+    remaining: usize,
+}
+
+impl StridedOffsets {
+    fn new(shape: &[usize], strides: &[usize], start: usize) -> Self {
+        StridedOffsets {
+            shape: shape.to_vec(),
+            strides: strides.to_vec(),
+            index: vec![0; shape.len()],
+            offset: start,
+            remaining: shape.iter().product(),
+        }
+    }
+}
+
+impl Iterator for StridedOffsets {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let current = self.offset;
+
+        // Step along the last axis, and carry over to the previous axis at the end of an axis
+        for axis in (0..self.shape.len()).rev() {
+            self.index[axis] += 1;
+            self.offset += self.strides[axis];
+            if self.index[axis] < self.shape[axis] {
+                break;
+            }
+            self.offset -= self.strides[axis] * self.shape[axis];
+            self.index[axis] = 0;
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Float> Tensor<T> {
+    /// Create a new tensor with the given shape
+    ///
+    /// All values within the tensor is 0.0.
+    pub fn new(shape: &[usize]) -> Self {
+        Self::full(shape, T::ZERO)
+    }
+
+    /// Create a new tensor with the given shape, where all values are the given value
+    pub fn full(shape: &[usize], value: T) -> Self {
+        Tensor {
+            data: vec![value; shape.iter().product()],
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+        }
+    }
+
+    /// Create a new tensor from values in row-major (C) order
+    ///
+    /// Returns `MatrixError::ShapeMismatch` if the amount of values does not fit the shape.
+    pub fn from_vec(shape: &[usize], data: Vec<T>) -> Result<Self, MatrixError> {
+        if shape.iter().product::<usize>() != data.len() {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: format_shape(shape),
+                second_matrix_shape: data.len().to_string(),
+            });
+        }
+
+        Ok(Tensor {
+            data,
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+        })
+    }
+
+    /// Get the length of each axis
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Get the distance in the data between two values next to each other along each axis
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Get the amount of dimensions (axes)
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Get the amount of values in the tensor
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if the tensor has no values
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Check if the values are stored in row-major (C) order
+    ///
+    /// A tensor is not contiguous after `permute`, until it is copied with `to_contiguous`.
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == contiguous_strides(&self.shape)
+    }
+
+    /// Get a value of the tensor
+    ///
+    /// The index must have one position for each axis.
+    /// Returns None if the index has the wrong amount of positions, or a position is out of range.
+    pub fn get(&self, index: &[usize]) -> Option<&T> {
+        self.offset(index).map(|offset| &self.data[offset])
+    }
+
+    /// Get a value of the tensor (as mutable reference)
+    ///
+    /// The index must have one position for each axis.
+    /// Returns None if the index has the wrong amount of positions, or a position is out of range.
+    pub fn get_mut(&mut self, index: &[usize]) -> Option<&mut T> {
+        self.offset(index).map(|offset| &mut self.data[offset])
+    }
+
+    /// Position in the data of the value at the index
+    fn offset(&self, index: &[usize]) -> Option<usize> {
+        if index.len() != self.ndim() || index.iter().zip(&self.shape).any(|(i, dim)| i >= dim) {
+            return None;
+        }
+        Some(index.iter().zip(&self.strides).map(|(i, s)| i * s).sum())
+    }
+
+    /// Iterate over the values in row-major order of the shape
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        StridedOffsets::new(&self.shape, &self.strides, 0).map(move |offset| &self.data[offset])
+    }
+
+    /// Get the values in row-major order of the shape
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+
+    /// Get a copy of the tensor with the values stored in row-major (C) order
+    pub fn to_contiguous(&self) -> Tensor<T> {
+        if self.is_contiguous() {
+            return self.clone();
+        }
+
+        Tensor {
+            data: self.to_vec(),
+            shape: self.shape.clone(),
+            strides: contiguous_strides(&self.shape),
+        }
+    }
+
+    /// Get a copy of the tensor with a new shape
+    ///
+    /// The values are read in row-major order, and fill the new shape in row-major order.
+    /// Returns `MatrixError::ShapeMismatch` if the new shape does not have the same amount of values.
+    pub fn reshape(&self, shape: &[usize]) -> Result<Tensor<T>, MatrixError> {
+        if shape.iter().product::<usize>() != self.len() {
+            return Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: format_shape(&self.shape),
+                second_matrix_shape: format_shape(shape),
+            });
+        }
+
+        Tensor::from_vec(shape, self.to_vec())
+    }
+
+    /// Get a copy of the tensor with the axes in a new order
+    ///
+    /// Axis `i` of the result is axis `axes[i]` of this tensor, for example `permute(&[0, 2, 3, 1])` turns `(N, C, H, W)` into `(N, H, W, C)`.
+    /// The values are copied in their current order, and only the shape and strides are reordered.
+    /// Returns `MatrixError::IllegalRange` if the axes are not a permutation of all axes of the tensor.
+    pub fn permute(&self, axes: &[usize]) -> Result<Tensor<T>, MatrixError> {
+        let mut sorted = axes.to_vec();
+        sorted.sort_unstable();
+        if sorted != (0..self.ndim()).collect::<Vec<usize>>() {
+            return Err(MatrixError::IllegalRange(format!(
+                "The axes {axes:?} are not a permutation of the {} axes of the tensor",
+                self.ndim()
+            )));
+        }
+
+        Ok(Tensor {
+            data: self.data.clone(),
+            shape: axes.iter().map(|axis| self.shape[*axis]).collect(),
+            strides: axes.iter().map(|axis| self.strides[*axis]).collect(),
+        })
+    }
+
+    /// Get a copy of a region of the tensor
+    ///
+    /// Ranges start from 0 and are not inclusive of the end value. The first range is for the first axis,
+    /// and the axes without a range are kept in full. The result has the same amount of axes.
+    /// Returns `MatrixError::IllegalRange` if there are more ranges than axes, or a range does not fit its axis.
+    pub fn slice(&self, ranges: &[Range<usize>]) -> Result<Tensor<T>, MatrixError> {
+        if ranges.len() > self.ndim() {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not slice {} axes of a tensor with {} axes",
+                ranges.len(),
+                self.ndim()
+            )));
+        }
+
+        let mut start = 0;
+        let mut shape = self.shape.clone();
+        for (axis, range) in ranges.iter().enumerate() {
+            if range.start > range.end || range.end > self.shape[axis] {
+                return Err(MatrixError::IllegalRange(format!(
+                    "The range {range:?} does not fit axis {axis} with length {}",
+                    self.shape[axis]
+                )));
+            }
+            start += range.start * self.strides[axis];
+            shape[axis] = range.len();
+        }
+
+        let data: Vec<T> = StridedOffsets::new(&shape, &self.strides, start)
+            .map(|offset| self.data[offset])
+            .collect();
+        Tensor::from_vec(&shape, data)
+    }
+
+    /// Get a copy of the values at one position along an axis, without that axis
+    ///
+    /// For example, `select(0, n)` on a `(N, C, H, W)` batch gives the `(C, H, W)` image `n`.
+    /// Returns `MatrixError::IllegalRange` if the axis or the position is out of range.
+    pub fn select(&self, axis: usize, index: usize) -> Result<Tensor<T>, MatrixError> {
+        if axis >= self.ndim() || index >= self.shape[axis] {
+            return Err(MatrixError::IllegalRange(format!(
+                "Can not select position {index} of axis {axis} in a tensor with shape {}",
+                format_shape(&self.shape)
+            )));
+        }
+
+        let mut shape = self.shape.clone();
+        let mut strides = self.strides.clone();
+        shape.remove(axis);
+        strides.remove(axis);
+
+        let data: Vec<T> = StridedOffsets::new(&shape, &strides, index * self.strides[axis])
+            .map(|offset| self.data[offset])
+            .collect();
+        Tensor::from_vec(&shape, data)
+    }
+
+    /// Create a new tensor by applying the function to each value
+    ///
+    /// The new tensor has the same shape, and may have another float type than this tensor.
+    pub fn map<U: Float, F: FnMut(T) -> U>(&self, mut operation: F) -> Tensor<U> {
+        Tensor {
+            data: self.iter().map(|x| operation(*x)).collect(),
+            shape: self.shape.clone(),
+            strides: contiguous_strides(&self.shape),
+        }
+    }
+
+    /// Scale all values in the tensor by a given scalar (`T`)
+    ///
+    /// Mutates the tensor and makes the change.
+    pub fn scale_f(&mut self, numb: T) {
+        for item in self.data.iter_mut() {
+            *item *= numb;
+        }
+    }
+
+    /// Sum all the values in the tensor
+    pub fn sum(&self) -> T {
+        self.data.iter().sum()
+    }
+
+    /// Find the shape that this tensor and the given tensor are broadcast to
+    ///
+    /// The shapes are aligned at the last axis, and each pair of dimensions must be equal, or one of them must be 1.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_shape(&self, tensor: &Tensor<T>) -> Result<Vec<usize>, MatrixError> {
+        broadcast_shapes(&self.shape, &tensor.shape)
+    }
+
+    /// Add two tensors element-wise with broadcasting
+    ///
+    /// See `broadcast_shape` for the shape of the result.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_add(&self, tensor: &Tensor<T>) -> Result<Tensor<T>, MatrixError> {
+        self.broadcast_with(tensor, |a, b| a + b)
+    }
+
+    /// Subtract two tensors element-wise with broadcasting
+    ///
+    /// See `broadcast_shape` for the shape of the result.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_sub(&self, tensor: &Tensor<T>) -> Result<Tensor<T>, MatrixError> {
+        self.broadcast_with(tensor, |a, b| a - b)
+    }
+
+    /// Multiply two tensors element-wise with broadcasting
+    ///
+    /// See `broadcast_shape` for the shape of the result.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_mul(&self, tensor: &Tensor<T>) -> Result<Tensor<T>, MatrixError> {
+        self.broadcast_with(tensor, |a, b| a * b)
+    }
+
+    /// Divide two tensors element-wise with broadcasting
+    ///
+    /// See `broadcast_shape` for the shape of the result. Dividing by zero follows the rules of `f32` and `f64`.
+    /// Returns `MatrixError::ShapeMismatch` if the shapes can not be broadcast together.
+    pub fn broadcast_div(&self, tensor: &Tensor<T>) -> Result<Tensor<T>, MatrixError> {
+        self.broadcast_with(tensor, |a, b| a / b)
+    }
+
+    /// Combine two tensors value by value after broadcasting them to the same shape
+    fn broadcast_with<F>(&self, tensor: &Tensor<T>, operation: F) -> Result<Tensor<T>, MatrixError>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let shape = self.broadcast_shape(tensor)?;
+        let lhs = StridedOffsets::new(
+            &shape,
+            &broadcast_strides(&self.shape, &self.strides, &shape),
+            0,
+        );
+        let rhs = StridedOffsets::new(
+            &shape,
+            &broadcast_strides(&tensor.shape, &tensor.strides, &shape),
+            0,
+        );
+
+        let data: Vec<T> = lhs
+            .zip(rhs)
+            .map(|(a, b)| operation(self.data[a], tensor.data[b]))
+            .collect();
+        Tensor::from_vec(&shape, data)
+    }
+
+    /// Batched matrix multiplication
+    ///
+    /// The last two axes of each tensor are matrices `(mxn)` and `(nxp)`, and the leading axes are batch axes.
+    /// The batch axes are broadcast, so a `(B, m, n)` tensor can be multiplied with a single `(n, p)` matrix.
+    /// The result has the broadcast batch axes followed by `(m, p)`. Each product uses `Matrix::multiply`.
+    /// Returns `MatrixError::DimensionMismatch` if a tensor has less than 2 axes,
+    /// `MatrixError::MatrixMultiply` if `n` does not match, and `MatrixError::ShapeMismatch` if the batch axes can not be broadcast.
+    pub fn matmul(&self, tensor: &Tensor<T>) -> Result<Tensor<T>, MatrixError> {
+        for ndim in [self.ndim(), tensor.ndim()] {
+            if ndim < 2 {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: 2,
+                    found: ndim,
+                });
+            }
+        }
+
+        let (lhs_batch, lhs_matrix) = self.shape.split_at(self.ndim() - 2);
+        let (rhs_batch, rhs_matrix) = tensor.shape.split_at(tensor.ndim() - 2);
+        if lhs_matrix[1] != rhs_matrix[0] {
+            return Err(MatrixError::MatrixMultiply);
+        }
+        let batch_shape = broadcast_shapes(lhs_batch, rhs_batch)?;
+
+        let lhs_starts = StridedOffsets::new(
+            &batch_shape,
+            &broadcast_strides(lhs_batch, &self.strides[..lhs_batch.len()], &batch_shape),
+            0,
+        );
+        let rhs_starts = StridedOffsets::new(
+            &batch_shape,
+            &broadcast_strides(rhs_batch, &tensor.strides[..rhs_batch.len()], &batch_shape),
+            0,
+        );
+
+        let mut data: Vec<T> = Vec::with_capacity(
+            batch_shape.iter().product::<usize>() * lhs_matrix[0] * rhs_matrix[1],
+        );
+        for (lhs_start, rhs_start) in lhs_starts.zip(rhs_starts) {
+            let lhs = self.matrix_at(lhs_start);
+            let rhs = tensor.matrix_at(rhs_start);
+            data.extend(lhs.multiply(&rhs)?.data);
+        }
+
+        let mut shape = batch_shape;
+        shape.extend_from_slice(&[lhs_matrix[0], rhs_matrix[1]]);
+        Tensor::from_vec(&shape, data)
+    }
+
+    /// Copy the matrix of the last two axes that starts at the given position in the data
+    fn matrix_at(&self, start: usize) -> Matrix<T> {
+        let ndim = self.ndim();
+        let (rows, cols) = (self.shape[ndim - 2], self.shape[ndim - 1]);
+        let data: Vec<T> = StridedOffsets::new(&[rows, cols], &self.strides[ndim - 2..], start)
+            .map(|offset| self.data[offset])
+            .collect();
+
+        Matrix { data, rows, cols }
+    }
+
+    /// Convert a tensor with two axes to a `Matrix`
+    ///
+    /// Returns `MatrixError::DimensionMismatch` if the tensor does not have exactly two axes.
+    pub fn to_matrix(&self) -> Result<Matrix<T>, MatrixError> {
+        if self.ndim() != 2 {
+            return Err(MatrixError::DimensionMismatch {
+                expected: 2,
+                found: self.ndim(),
+            });
+        }
+
+        Ok(Matrix {
+            data: self.to_vec(),
+            rows: self.shape[0],
+            cols: self.shape[1],
+        })
+    }
+}
+
+// Two tensors are equal if they have the same shape and values, even if the values are stored in another order
+impl<T: Float> PartialEq for Tensor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Float> From<Matrix<T>> for Tensor<T> {
+    fn from(mat: Matrix<T>) -> Self {
+        let shape = [mat.rows, mat.cols];
+        Tensor {
+            data: mat.data,
+            shape: shape.to_vec(),
+            strides: contiguous_strides(&shape),
+        }
+    }
+}
+
+impl<T: Float> From<&Matrix<T>> for Tensor<T> {
+    fn from(mat: &Matrix<T>) -> Self {
+        Tensor::from(mat.clone())
+    }
+}
+
+// Indexing with `tensor[&[n, c, h, w]]`
+//
+// Panics if the index is out of bounds, in the same way as indexing a slice.
+// Use `get` or `get_mut` to get an `Option` instead.
+impl<T: Float> Index<&[usize]> for Tensor<T> {
+    type Output = T;
+
+    fn index(&self, index: &[usize]) -> &T {
+        match self.offset(index) {
+            Some(offset) => &self.data[offset],
+            None => panic!(
+                "index out of bounds: the tensor has shape {} but the index is {index:?}",
+                format_shape(&self.shape)
+            ),
+        }
+    }
+}
+
+impl<T: Float> IndexMut<&[usize]> for Tensor<T> {
+    fn index_mut(&mut self, index: &[usize]) -> &mut T {
+        match self.offset(index) {
+            Some(offset) => &mut self.data[offset],
+            None => panic!(
+                "index out of bounds: the tensor has shape {} but the index is {index:?}",
+                format_shape(&self.shape)
+            ),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use rustic_ml::data_utils::convolution::Padding;
+    use rustic_ml::data_utils::matrix::{Matrix, MatrixError};
+
+    /// Direct convolution with the same rules as `conv2d`, used to check the im2col result
+    fn direct_conv2d(
+        input: &Matrix,
+        kernel: &Matrix,
+        stride: usize,
+        pad: (usize, usize),
+    ) -> Matrix {
+        let padded_rows = input.rows + 2 * pad.0;
+        let padded_cols = input.cols + 2 * pad.1;
+        let out_rows = (padded_rows - kernel.rows) / stride + 1;
+        let out_cols = (padded_cols - kernel.cols) / stride + 1;
+
+        let mut result: Matrix = Matrix::new(out_rows, out_cols);
+        for i in 0..out_rows {
+            for j in 0..out_cols {
+                let mut sum = 0.0;
+                for a in 0..kernel.rows {
+                    for b in 0..kernel.cols {
+                        let row = (i * stride + a) as isize - pad.0 as isize;
+                        let col = (j * stride + b) as isize - pad.1 as isize;
+                        if row < 0 || col < 0 {
+                            continue;
+                        }
+                        if let Some(value) = input.get(row as usize, col as usize) {
+                            sum += value * kernel[(a, b)];
+                        }
+                    }
+                }
+                result[(i, j)] = sum;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_conv2d_valid() {
+        let input: Matrix = Matrix::from_vec(4, (0..16).map(|x| x as f32).collect());
+        let kernel: Matrix = Matrix::from_vec(2, vec![1.0, 0.0, 0.0, -1.0]);
+
+        let result = input.conv2d(&kernel, 1, Padding::Valid).unwrap();
+        assert_eq!(result.shape(), "3x3");
+        assert_eq!(result.data, vec![-5.0; 9]);
+
+        let strided = input.conv2d(&kernel, 2, Padding::Valid).unwrap();
+        assert_eq!(strided.shape(), "2x2");
+        assert_eq!(strided, direct_conv2d(&input, &kernel, 2, (0, 0)));
+    }
+
+    #[test]
+    fn test_conv2d_matches_direct() {
+        let input: Matrix = Matrix::with_rand_range_seeded(9, 7, -1.0..=1.0, 5);
+        let kernel: Matrix = Matrix::with_rand_range_seeded(3, 2, -1.0..=1.0, 6);
+
+        for stride in 1..4 {
+            for pad in 0..3 {
+                let result = input.conv2d(&kernel, stride, Padding::Zeros(pad)).unwrap();
+                let expected = direct_conv2d(&input, &kernel, stride, (pad, pad));
+                assert!(
+                    result.approx_eq(&expected, 1e-5),
+                    "stride {stride}, padding {pad}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv2d_same() {
+        let input: Matrix = Matrix::with_rand_range_seeded(5, 6, 0.0..=1.0, 1);
+        let kernel: Matrix = Matrix::from_vec(3, vec![1.0; 9]);
+
+        let same = input.conv2d(&kernel, 1, Padding::Same).unwrap();
+        assert_eq!(same.shape(), input.shape());
+        assert!(same.approx_eq(&direct_conv2d(&input, &kernel, 1, (1, 1)), 1e-5));
+
+        // The output has ceil(input / stride) rows and columns
+        assert_eq!(
+            input.conv2d(&kernel, 2, Padding::Same).unwrap().shape(),
+            "3x3"
+        );
+        assert_eq!(
+            input.conv2d(&kernel, 4, Padding::Same).unwrap().shape(),
+            "2x2"
+        );
+
+        // Even kernels pad more at the bottom and right
+        let corner: Matrix = Matrix::from_vec(2, vec![1.0; 4]);
+        let result = input.conv2d(&corner, 1, Padding::Same).unwrap();
+        assert_eq!(result.shape(), input.shape());
+        let last = input[(4, 5)];
+        assert!((result[(4, 5)] - last).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_conv2d_filters() {
+        // A vertical edge between the dark left half and the bright right half
+        let mut image: Matrix = Matrix::new(5, 6);
+        for row in 0..5 {
+            for col in 3..6 {
+                image[(row, col)] = 1.0;
+            }
+        }
+
+        let sobel_x: Matrix =
+            Matrix::from_vec(3, vec![-1.0, 0.0, 1.0, -2.0, 0.0, 2.0, -1.0, 0.0, 1.0]);
+        let edges = image.conv2d(&sobel_x, 1, Padding::Valid).unwrap();
+        assert_eq!(edges.get_row(0), Some(vec![0.0, 4.0, 4.0, 0.0]));
+
+        // A normalized Gaussian blur keeps a constant image constant
+        let gaussian =
+            Matrix::from_vec(3, vec![1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0]) / 16.0;
+        let constant: Matrix = Matrix::from_vec(4, vec![3.0; 16]);
+        let blurred = constant.conv2d(&gaussian, 1, Padding::Valid).unwrap();
+        assert!(blurred.approx_eq(&Matrix::from_vec(2, vec![3.0; 4]), 1e-6));
+    }
+
+    #[test]
+    fn test_conv2d_errors() {
+        let input: Matrix = Matrix::new(3, 3);
+        let kernel: Matrix = Matrix::new(4, 2);
+
+        assert_eq!(
+            input.conv2d(&kernel, 1, Padding::Valid),
+            Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: "3x3".to_string(),
+                second_matrix_shape: "4x2".to_string(),
+            })
+        );
+        assert!(input.conv2d(&kernel, 1, Padding::Zeros(1)).is_ok());
+        assert!(matches!(
+            input.conv2d(&Matrix::new(2, 2), 0, Padding::Valid),
+            Err(MatrixError::IllegalRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_im2col() {
+        let input: Matrix = Matrix::from_vec(3, (1..=9).map(|x| x as f32).collect());
+
+        let patches = input.im2col(2, 2, 1, Padding::Valid).unwrap();
+        assert_eq!(patches.shape(), "4x4");
+        assert_eq!(patches.get_row(0), Some(vec![1.0, 2.0, 4.0, 5.0]));
+        assert_eq!(patches.get_row(3), Some(vec![5.0, 6.0, 8.0, 9.0]));
+
+        let padded = input.im2col(3, 3, 3, Padding::Zeros(1)).unwrap();
+        assert_eq!(padded.shape(), "1x9");
+        assert_eq!(
+            padded.data,
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 0.0, 4.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn test_pooling() {
+        let input: Matrix = Matrix::from_vec(
+            4,
+            vec![
+                1.0, 3.0, 2.0, 0.0, //
+                5.0, 4.0, 1.0, 1.0, //
+                0.0, -1.0, 8.0, 6.0, //
+                2.0, 2.0, 7.0, 3.0,
+            ],
+        );
+
+        let max = input.max_pool2d(2, 2, 2).unwrap();
+        assert_eq!(max.shape(), "2x2");
+        assert_eq!(max.data, vec![5.0, 2.0, 2.0, 8.0]);
+
+        let avg = input.avg_pool2d(2, 2, 2).unwrap();
+        assert_eq!(avg.data, vec![3.25, 1.0, 0.75, 6.0]);
+
+        // Overlapping windows, and windows that do not fit are left out
+        let overlapping = input.max_pool2d(3, 3, 1).unwrap();
+        assert_eq!(overlapping.data, vec![8.0, 8.0, 8.0, 8.0]);
+        assert_eq!(input.max_pool2d(3, 3, 2).unwrap().shape(), "1x1");
+
+        let negative = Matrix::from_vec_typed(2, vec![-4.0_f64, -3.0, -2.0, -1.0]);
+        assert_eq!(negative.max_pool2d(2, 2, 1).unwrap().data, vec![-1.0]);
+
+        assert!(input.avg_pool2d(5, 1, 1).is_err());
+        assert!(input.max_pool2d(2, 2, 0).is_err());
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests {
+    use rustic_ml::data_utils::csv::{
+        CsvDialect, CsvError, CsvReader, CsvRecord, CsvWriter, QuoteStyle,
+    };
+    use rustic_ml::data_utils::dataframe::Dataframe;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rustic_ml_{}_{name}", std::process::id()))
+    }
+
+    fn read_all(input: &str, dialect: CsvDialect) -> Result<Vec<Vec<String>>, CsvError> {
+        CsvReader::new(input.as_bytes(), dialect)
+            .map(|record| record.map(|record| record.fields))
+            .collect()
+    }
+
+    #[test]
+    fn test_read_simple_records() {
+        let records = read_all("a,b,c\n1,2,3\r\n4,,6", CsvDialect::default()).unwrap();
+
+        assert_eq!(
+            records,
+            vec![vec!["a", "b", "c"], vec!["1", "2", "3"], vec!["4", "", "6"]]
+        );
+    }
+
+    #[test]
+    fn test_read_quoted_fields() {
+        let input =
+            "name;comment\nOslo;\"cold; but \"\"nice\"\"\"\n\"Bergen\";\"rain\nand more rain\"\n";
+        let mut reader = CsvReader::new(input.as_bytes(), CsvDialect::new(';'));
+
+        reader.read_record().unwrap();
+        assert_eq!(
+            reader.read_record().unwrap(),
+            Some(CsvRecord {
+                line: 2,
+                fields: vec!["Oslo".to_string(), "cold; but \"nice\"".to_string()],
+                quoted: vec![false, true],
+            })
+        );
+        assert_eq!(
+            reader.read_record().unwrap(),
+            Some(CsvRecord {
+                line: 3,
+                fields: vec!["Bergen".to_string(), "rain\nand more rain".to_string()],
+                quoted: vec![true, true],
+            })
+        );
+        assert!(reader.read_record().unwrap().is_none());
+        assert_eq!(reader.line(), 4);
+    }
+
+    #[test]
+    fn test_read_custom_quote_and_escape() {
+        let dialect = CsvDialect::new(',')
+            .with_quote(Some('\''))
+            .with_escape(Some('\\'));
+        let records = read_all("'it\\'s',\"x\"\n'a\\\\b',''''\n", dialect).unwrap();
+
+        assert_eq!(records, vec![vec!["it's", "\"x\""], vec!["a\\b", "'"]]);
+
+        // Without a quote character, quotes are text
+        let dialect = CsvDialect::new(',').with_quote(None);
+        let records = read_all("\"a,b\"", dialect).unwrap();
+        assert_eq!(records, vec![vec!["\"a", "b\""]]);
+    }
+
+    #[test]
+    fn test_ragged_row() {
+        let input = "a,b,c\n1,2,3\n\n4,5\n7,8,9\n";
+        let mut reader = CsvReader::new(input.as_bytes(), CsvDialect::default());
+
+        reader.read_record().unwrap();
+        reader.read_record().unwrap();
+        match reader.read_record() {
+            Err(CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            }) => assert_eq!((line, expected, found), (4, 3, 2)),
+            other => panic!("Expected a ragged row, got {other:?}"),
+        }
+
+        // Reading continues after the ragged row
+        assert_eq!(reader.read_record().unwrap().unwrap().line, 5);
+    }
+
+    #[test]
+    fn test_malformed_fields() {
+        let error = read_all("a,b\n1,x\"y\"\n", CsvDialect::default()).unwrap_err();
+        assert!(matches!(
+            error,
+            CsvError::MalformedField { line: 2, field: 2 }
+        ));
+
+        let error = read_all("a,b\n\"1\"x,2\n", CsvDialect::default()).unwrap_err();
+        assert!(matches!(
+            error,
+            CsvError::MalformedField { line: 2, field: 1 }
+        ));
+        assert_eq!(error.to_string(), "Line 2: field 1 has a misplaced quote");
+    }
+
+    #[test]
+    fn test_recover_after_malformed_record() {
+        // The misplaced quote comes before a quoted field that spans two lines
+        let input = "a,b,c\na\"b,\"x\ny\",1\n\"ok\"x,\"p\nq\",2\n3,4,5\n";
+        let mut reader = CsvReader::new(input.as_bytes(), CsvDialect::default());
+
+        reader.read_record().unwrap();
+        assert!(matches!(
+            reader.read_record(),
+            Err(CsvError::MalformedField { line: 2, field: 1 })
+        ));
+        assert_eq!(reader.line(), 3);
+        assert!(matches!(
+            reader.read_record(),
+            Err(CsvError::MalformedField { line: 4, field: 1 })
+        ));
+        assert_eq!(
+            reader.read_record().unwrap(),
+            Some(CsvRecord {
+                line: 6,
+                fields: vec!["3".to_string(), "4".to_string(), "5".to_string()],
+                quoted: vec![false; 3],
+            })
+        );
+        assert!(reader.read_record().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_unterminated_quote() {
+        let error = read_all("a,b\n1,\"open\n\nstill open\n", CsvDialect::default()).unwrap_err();
+
+        assert!(matches!(
+            error,
+            CsvError::UnterminatedQuote { line: 2, field: 2 }
+        ));
+    }
+
+    #[test]
+    fn test_dataframe_quoted_text() {
+        let path = temp_path("quoted.csv");
+        std::fs::write(
+            &path,
+            "id;comment;score\n1;\"ok; shipped\";1.5\n2;\"line\nbreak\";2.5\n",
+        )
+        .unwrap();
+
+        let dataframe = Dataframe::from_csv(path.to_string_lossy().into_owned());
+        std::fs::remove_file(&path).unwrap();
+        let dataframe = dataframe.unwrap();
+
+        assert_eq!(dataframe.column_names(), vec!["id", "comment", "score"]);
+        assert_eq!(
+            dataframe.at_str("comment", 0).unwrap(),
+            Some("ok; shipped".to_string())
+        );
+        assert_eq!(
+            dataframe.at_str("comment", 1).unwrap(),
+            Some("line\nbreak".to_string())
+        );
+        assert_eq!(
+            dataframe.at_str("score", 1).unwrap(),
+            Some("2.5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_dataframe_ragged_row() {
+        let path = temp_path("ragged.csv");
+        std::fs::write(&path, "a;b\n1;2\n3\n").unwrap();
+
+        let dataframe = Dataframe::from_csv(path.to_string_lossy().into_owned());
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(dataframe.is_err());
+    }
+
+    #[test]
+    fn test_write_quoting() {
+        let records = vec![
+            vec!["plain", "with,comma", "with \"quote\"", "line\nbreak", ""],
+            vec!["", "", "", "", ""],
+        ];
+
+        let mut writer = CsvWriter::new(Vec::new(), CsvDialect::default(), QuoteStyle::Necessary);
+        for record in &records {
+            writer.write_record(record).unwrap();
+        }
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(
+            output,
+            "plain,\"with,comma\",\"with \"\"quote\"\"\",\"line\nbreak\",\n,,,,\n"
+        );
+
+        // The written records are read back as the same fields
+        assert_eq!(read_all(&output, CsvDialect::default()).unwrap(), records);
+
+        let mut writer = CsvWriter::new(Vec::new(), CsvDialect::default(), QuoteStyle::Always);
+        writer.write_record(["a", "b"]).unwrap();
+        let mut writer = CsvWriter::new(
+            writer.into_inner(),
+            CsvDialect::default(),
+            QuoteStyle::Never,
+        );
+        writer.write_record(["a,b", "\"c\""]).unwrap();
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(output, "\"a\",\"b\"\na,b,\"c\"\n");
+    }
+
+    #[test]
+    fn test_write_escape_and_single_empty_field() {
+        let dialect = CsvDialect::new(';').with_escape(Some('\\'));
+        let records = vec![vec!["say \"hi\"", "back\\slash"], vec!["", "x"]];
+
+        let mut writer = CsvWriter::new(Vec::new(), dialect, QuoteStyle::Necessary);
+        for record in &records {
+            writer.write_record(record).unwrap();
+        }
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(output, "\"say \\\"hi\\\"\";\"back\\\\slash\"\n;x\n");
+        assert_eq!(read_all(&output, dialect).unwrap(), records);
+
+        // A single empty field is quoted, so it is not read as an empty line
+        let mut writer = CsvWriter::new(Vec::new(), CsvDialect::default(), QuoteStyle::Necessary);
+        writer.write_record(["name"]).unwrap();
+        writer.write_record([""]).unwrap();
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(output, "name\n\"\"\n");
+        assert_eq!(
+            read_all(&output, CsvDialect::default()).unwrap(),
+            vec![vec!["name"], vec![""]]
+        );
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use rustic_ml::data_utils::dataframe::{ColumnType, Dataframe};
+    use rustic_ml::data_utils::csv::{
+        CsvDialect, CsvError, CsvReadOptions, CsvReader, CsvWriteOptions, QuoteStyle,
+    };
+    use rustic_ml::data_utils::dataframe::{ColumnType, Dataframe, DataframeError};
 
     #[test]
     fn test_from_csv() {
@@ -11,9 +14,37 @@ mod tests {
 
     #[test]
     fn test_from_file() {
-        let path = String::from("./datasets/european_cities.txt");
+        let path = String::from("./datasets/european_cities_quoted.txt");
         let dataframe = Dataframe::from_file(path, ' ');
-        assert!(dataframe.is_ok())
+        assert!(dataframe.is_ok());
+
+        let columns = dataframe.unwrap().column_names();
+        assert_eq!(columns.len(), 24);
+        assert_eq!(columns[19], "Saint Petersburg");
+    }
+
+    #[test]
+    fn test_from_file_unquoted_header() {
+        // The unquoted name `Saint Petersburg` gives the header 25 fields, and the rows 24
+        let path = String::from("./datasets/european_cities.txt");
+        let file = std::fs::File::open(&path).unwrap();
+        let mut reader = CsvReader::new(std::io::BufReader::new(file), CsvDialect::new(' '));
+
+        assert_eq!(reader.read_record().unwrap().unwrap().fields.len(), 25);
+        match reader.read_record() {
+            Err(CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            }) => assert_eq!((line, expected, found), (2, 25, 24)),
+            other => panic!("Expected a ragged row, got {other:?}"),
+        }
+
+        match Dataframe::from_file(path, ' ') {
+            Err(DataframeError::Parse { row, col, .. }) => assert_eq!((row, col), (2, 25)),
+            Err(err) => panic!("Expected a parse error, got {err}"),
+            Ok(_) => panic!("Expected a parse error for the unquoted header"),
+        }
     }
 
     #[test]
@@ -62,7 +93,7 @@ mod tests {
         assert!(dataframe.has_column("Barcelona"));
         assert!(!dataframe.has_column("Oslo"));
 
-        dataframe.rename_column(0, "Oslo");
+        dataframe.rename_column(0, "Oslo").unwrap();
         assert!(dataframe.has_column("Oslo"));
     }
 
@@ -115,7 +146,7 @@ mod tests {
 
         assert!(dataframe.has_column("Barcelona"));
 
-        dataframe.drop_column("Barcelona");
+        dataframe.drop_column("Barcelona").unwrap();
         assert!(!dataframe.has_column("Barcelona"));
     }
 
@@ -125,7 +156,9 @@ mod tests {
         let mut dataframe = Dataframe::from_csv(path).unwrap();
         assert!(!dataframe.has_column("custom_index_column"));
 
-        dataframe.add_column(vec![1, 2, 3, 4], "custom_index_column");
+        dataframe
+            .add_column((0..24).collect(), "custom_index_column")
+            .unwrap();
         assert!(dataframe.has_column("custom_index_column"));
     }
 
@@ -137,8 +170,11 @@ mod tests {
         assert!(dataframe.has_column("Barcelona"));
         assert!(!dataframe.has_column("Oslo"));
 
-        assert!(dataframe.get_column_type("Barcelona") == Some(ColumnType::Float));
-        assert!(dataframe.get_column_type("Oslo") == None);
+        assert!(dataframe.get_column_type("Barcelona").unwrap() == ColumnType::Float);
+        assert!(matches!(
+            dataframe.get_column_type("Oslo"),
+            Err(DataframeError::MissingColumn(name)) if name == "Oslo"
+        ));
     }
 
     #[test]
@@ -146,15 +182,21 @@ mod tests {
         let path = String::from("./datasets/european_cities.csv");
         let dataframe = Dataframe::from_csv(path).unwrap();
 
-        assert!(dataframe.at_str("Barcelona", 2) == Some("1497.61".to_string()));
-        assert!(dataframe.at_str("Berlin", 1) == Some("999.25".to_string()));
-        assert!(dataframe.at_str("Paris", 5) == Some("1247.61".to_string()));
-        assert!(dataframe.at_str("Warsaw", 23) == Some("0".to_string()));
-        assert!(dataframe.at_str("Brussels", 2) == Some("651.62".to_string()));
+        assert!(dataframe.at_str("Barcelona", 2).unwrap() == Some("1497.61".to_string()));
+        assert!(dataframe.at_str("Berlin", 1).unwrap() == Some("999.25".to_string()));
+        assert!(dataframe.at_str("Paris", 5).unwrap() == Some("1247.61".to_string()));
+        assert!(dataframe.at_str("Warsaw", 23).unwrap() == Some("0".to_string()));
+        assert!(dataframe.at_str("Brussels", 2).unwrap() == Some("651.62".to_string()));
 
-        assert!(dataframe.at_str("Oslo", 1) == None);
-        assert!(dataframe.at_str("Brussels", 50) == None);
-        assert!(dataframe.at_str("America", 5) == None);
+        assert!(dataframe.at_str("Brussels", 50).unwrap().is_none());
+        assert!(matches!(
+            dataframe.at_str("Oslo", 1),
+            Err(DataframeError::MissingColumn(name)) if name == "Oslo"
+        ));
+        assert!(matches!(
+            dataframe.at_str("America", 5),
+            Err(DataframeError::MissingColumn(_))
+        ));
     }
 
     #[test]
@@ -162,10 +204,401 @@ mod tests {
         let path = String::from("./datasets/european_cities.csv");
         let dataframe = Dataframe::from_csv(path).unwrap();
 
-        assert!(dataframe.at_index_str(2) == Some("1497.61".to_string()));
-        assert!(dataframe.at_index_str(24) == Some("1528.13".to_string()));
-        assert!(dataframe.at_index_str(49) == Some("999.25".to_string()));
+        assert!(dataframe.at_index_str(2).unwrap() == Some("1497.61".to_string()));
+        assert!(dataframe.at_index_str(24).unwrap() == Some("1528.13".to_string()));
+        assert!(dataframe.at_index_str(49).unwrap() == Some("999.25".to_string()));
+
+        assert!(dataframe
+            .at_index_str((24 * 24 + 1) as usize)
+            .unwrap()
+            .is_none());
+
+        // Without columns there is no value at any index
+        let mut dataframe = dataframe;
+        for column in dataframe.column_names() {
+            dataframe.drop_column(&column).unwrap();
+        }
+        assert!(matches!(
+            dataframe.at_index_str(0),
+            Err(DataframeError::MissingColumn(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        let path = String::from("./datasets/does_not_exist.csv");
+        assert!(matches!(
+            Dataframe::from_csv(path),
+            Err(DataframeError::Io(_))
+        ));
+
+        let path =
+            std::env::temp_dir().join(format!("rustic_ml_{}_ragged.csv", std::process::id()));
+        std::fs::write(&path, "a;b\n1;2\n3;4;5\n").unwrap();
+        let dataframe = Dataframe::from_csv(path.to_string_lossy().into_owned());
+        std::fs::remove_file(&path).unwrap();
+
+        match dataframe {
+            Err(DataframeError::Parse { row, col, .. }) => assert_eq!((row, col), (3, 3)),
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_column_errors() {
+        let path = String::from("./datasets/european_cities.csv");
+        let mut dataframe = Dataframe::from_csv(path).unwrap();
+
+        assert!(matches!(
+            dataframe.rename_column(24, "Oslo"),
+            Err(DataframeError::MissingColumn(_))
+        ));
+        assert!(matches!(
+            dataframe.drop_column("Oslo"),
+            Err(DataframeError::MissingColumn(_))
+        ));
+        assert!(matches!(
+            dataframe.add_column(vec![1, 2, 3], "short"),
+            Err(DataframeError::LengthMismatch {
+                expected: 24,
+                found: 3
+            })
+        ));
 
-        assert!(dataframe.at_index_str((24 * 24 + 1) as usize) == None)
+        dataframe.add_column(vec!["a"; 24], "text_column").unwrap();
+        assert!(matches!(
+            dataframe.float_feature("text_column"),
+            Err(DataframeError::TypeMismatch {
+                expected: ColumnType::Float,
+                found: ColumnType::Text,
+                ..
+            })
+        ));
+        assert!(matches!(
+            dataframe.float_features("Barcelona", "Oslo"),
+            Err(DataframeError::MissingColumn(_))
+        ));
+        assert_eq!(
+            dataframe.float_features("Barcelona", "Berlin").unwrap()[1],
+            Some((1528.13, 999.25))
+        );
+    }
+
+    #[test]
+    fn test_to_csv_round_trip() {
+        let path = String::from("./datasets/european_cities.csv");
+        let dataframe = Dataframe::from_csv(path).unwrap();
+
+        let copy_path = std::env::temp_dir().join(format!(
+            "rustic_ml_{}_european_cities.csv",
+            std::process::id()
+        ));
+        dataframe
+            .to_csv(copy_path.to_string_lossy().into_owned())
+            .unwrap();
+        let copy = Dataframe::from_csv(copy_path.to_string_lossy().into_owned());
+        std::fs::remove_file(&copy_path).unwrap();
+        let copy = copy.unwrap();
+
+        assert_eq!(copy.column_names(), dataframe.column_names());
+        assert_eq!(copy.memory_usage(), dataframe.memory_usage());
+        for column in dataframe.column_names() {
+            assert_eq!(copy.get_column_type(&column).unwrap(), ColumnType::Float);
+            for row in 0..24 {
+                assert_eq!(
+                    copy.at_str(&column, row).unwrap(),
+                    dataframe.at_str(&column, row).unwrap()
+                );
+            }
+        }
+
+        // A missing text is written empty, and an empty text is quoted
+        let input = "city;code\nOslo;1\nNA;\n;3\n";
+        let options = CsvReadOptions::new()
+            .with_delimiter(';')
+            .with_null_tokens(&["NA"]);
+        let dataframe = Dataframe::from_reader(input.as_bytes(), &options).unwrap();
+        dataframe
+            .to_csv(copy_path.to_string_lossy().into_owned())
+            .unwrap();
+        let written = std::fs::read_to_string(&copy_path).unwrap();
+        let options = CsvReadOptions::new()
+            .with_delimiter(';')
+            .with_null_tokens(&[""]);
+        let copy =
+            Dataframe::from_file_with_options(copy_path.to_string_lossy().into_owned(), &options);
+        std::fs::remove_file(&copy_path).unwrap();
+        let copy = copy.unwrap();
+
+        assert_eq!(written, "city;code\nOslo;1\n;\n\"\";3\n");
+        assert_eq!(copy.get_column_type("city").unwrap(), ColumnType::Text);
+        for column in ["city", "code"] {
+            for row in 0..3 {
+                assert_eq!(
+                    copy.at_str(column, row).unwrap(),
+                    dataframe.at_str(column, row).unwrap()
+                );
+            }
+        }
+        assert!(copy.at_str("city", 1).unwrap().is_none());
+        assert_eq!(copy.at_str("city", 2).unwrap(), Some(String::new()));
+    }
+
+    #[test]
+    fn test_to_writer_options() {
+        let path = String::from("./datasets/european_cities.csv");
+        let mut dataframe = Dataframe::from_csv(path).unwrap();
+        for column in dataframe.column_names().iter().skip(2) {
+            dataframe.drop_column(column).unwrap();
+        }
+        dataframe
+            .add_column(vec!["a \"quoted\"\ttab"; 24], "text")
+            .unwrap();
+
+        let mut output: Vec<u8> = Vec::new();
+        let options = CsvWriteOptions::new()
+            .with_delimiter('\t')
+            .with_null_value("NA");
+        dataframe.to_writer(&mut output, &options).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("Barcelona\tBelgrade\ttext"));
+        assert_eq!(lines.next(), Some("0\t1528.13\t\"a \"\"quoted\"\"\ttab\""));
+        assert_eq!(text.lines().count(), 25);
+
+        let mut output: Vec<u8> = Vec::new();
+        let options = CsvWriteOptions::new()
+            .with_header(false)
+            .with_quote_style(QuoteStyle::Always);
+        dataframe.to_writer(&mut output, &options).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(
+            text.lines().next(),
+            Some("\"0\",\"1528.13\",\"a \"\"quoted\"\"\ttab\"")
+        );
+        assert_eq!(text.lines().count(), 24);
+    }
+
+    #[test]
+    fn test_read_options() {
+        let input = "Exported 2024-01-01\n# comment\ncity;temperature;coastal\nOslo ; 4,5;true\n# another comment\nBergen;NA;true\n ? ;-1,25;?\n";
+        let options = CsvReadOptions::new()
+            .with_delimiter(';')
+            .with_skip_rows(1)
+            .with_comment_prefix("#")
+            .with_null_tokens(&["NA", "?"])
+            .with_decimal_separator(',')
+            .with_trim(true);
+        let dataframe = Dataframe::from_reader(input.as_bytes(), &options).unwrap();
+
+        assert_eq!(
+            dataframe.column_names(),
+            vec!["city", "temperature", "coastal"]
+        );
+        assert_eq!(dataframe.get_column_type("city").unwrap(), ColumnType::Text);
+        assert_eq!(
+            dataframe.get_column_type("temperature").unwrap(),
+            ColumnType::Float
+        );
+        assert_eq!(
+            dataframe.get_column_type("coastal").unwrap(),
+            ColumnType::Boolean
+        );
+        assert_eq!(
+            dataframe.at_str("city", 0).unwrap(),
+            Some("Oslo".to_string())
+        );
+        assert_eq!(dataframe.at_str("city", 2).unwrap(), None);
+        assert_eq!(
+            dataframe.at_str("temperature", 0).unwrap(),
+            Some("4.5".to_string())
+        );
+        assert_eq!(dataframe.at_str("temperature", 1).unwrap(), None);
+        assert_eq!(
+            dataframe.at_str("temperature", 2).unwrap(),
+            Some("-1.25".to_string())
+        );
+        assert_eq!(dataframe.at_str("coastal", 2).unwrap(), None);
+
+        // Without trimming, text keeps its whitespace
+        let dataframe =
+            Dataframe::from_reader(input.as_bytes(), &options.with_trim(false)).unwrap();
+        assert_eq!(
+            dataframe.at_str("city", 0).unwrap(),
+            Some("Oslo ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read_options_schema() {
+        let input = "1;2.5;x\n2;3;y\n3;4;z\n";
+        let options = CsvReadOptions::new()
+            .with_delimiter(';')
+            .with_header(false)
+            .with_max_rows(2);
+
+        let dataframe = Dataframe::from_reader(input.as_bytes(), &options).unwrap();
+        assert_eq!(
+            dataframe.column_names(),
+            vec!["column_0", "column_1", "column_2"]
+        );
+        assert_eq!(
+            dataframe.at_str("column_0", 1).unwrap(),
+            Some("2".to_string())
+        );
+        assert_eq!(dataframe.at_str("column_0", 2).unwrap(), None);
+
+        let schema = [
+            ("id", ColumnType::Float),
+            ("value", ColumnType::Float),
+            ("label", ColumnType::Text),
+        ];
+        let dataframe =
+            Dataframe::from_reader(input.as_bytes(), &options.clone().with_schema(&schema))
+                .unwrap();
+        assert_eq!(dataframe.column_names(), vec!["id", "value", "label"]);
+        assert_eq!(dataframe.get_column_type("id").unwrap(), ColumnType::Float);
+
+        // Values that do not match the given type are errors with their row and column
+        let options = options.with_column_type("column_2", ColumnType::Integer);
+        match Dataframe::from_reader(input.as_bytes(), &options) {
+            Err(DataframeError::Parse { row, col, .. }) => assert_eq!((row, col), (1, 3)),
+            _ => panic!("Expected a parse error"),
+        }
+
+        let options = CsvReadOptions::new().with_column_type("missing", ColumnType::Text);
+        assert!(matches!(
+            Dataframe::from_reader("a,b\n1,2\n".as_bytes(), &options),
+            Err(DataframeError::MissingColumn(_))
+        ));
+
+        let options = CsvReadOptions::new().with_schema(&[("a", ColumnType::Integer)]);
+        assert!(matches!(
+            Dataframe::from_reader("a,b\n1,2\n".as_bytes(), &options),
+            Err(DataframeError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_chunks_from_file() {
+        let path = String::from("./datasets/european_cities.csv");
+        let dataframe = Dataframe::from_csv(path.clone()).unwrap();
+        let options = CsvReadOptions::new().with_delimiter(';');
+
+        let chunks: Vec<Dataframe> = Dataframe::chunks_from_file(path, &options, 10)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let sizes: Vec<usize> = chunks.iter().map(|chunk| chunk.row_count()).collect();
+        assert_eq!(sizes, vec![10, 10, 4]);
+        for (index, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.column_names(), dataframe.column_names());
+            for row in 0..chunk.row_count() {
+                assert_eq!(
+                    chunk.at_str("Paris", row).unwrap(),
+                    dataframe.at_str("Paris", index * 10 + row).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunks_from_reader() {
+        let mut input = String::from("id,value,flag\n");
+        for index in 0..1000 {
+            input.push_str(&format!("{index},{}.5,{}\n", index * 2, index % 2 == 0));
+        }
+
+        let options = CsvReadOptions::new().with_max_rows(950);
+        let mut chunks = Dataframe::chunks_from_reader(input.as_bytes(), &options, 100).unwrap();
+        assert_eq!(
+            chunks.column_types(),
+            &[ColumnType::Integer, ColumnType::Float, ColumnType::Boolean]
+        );
+
+        let mut rows = 0;
+        for chunk in chunks.by_ref() {
+            let chunk = chunk.unwrap();
+            assert_eq!(chunk.get_column_type("id").unwrap(), ColumnType::Integer);
+            assert_eq!(chunk.at_str("id", 0).unwrap(), Some(rows.to_string()));
+            rows += chunk.row_count();
+        }
+        assert_eq!(rows, 950);
+        assert!(chunks.next().is_none());
+
+        // Values after the sample must fit the inferred types
+        let input = "id,value\n1,2\n2,3\n3,x\n4,5\n";
+        let options = CsvReadOptions::new().with_infer_rows(2);
+        let mut chunks = Dataframe::chunks_from_reader(input.as_bytes(), &options, 1).unwrap();
+        assert!(chunks.next().unwrap().is_ok());
+        assert!(chunks.next().unwrap().is_ok());
+        match chunks.next() {
+            Some(Err(DataframeError::Parse { row, col, .. })) => assert_eq!((row, col), (4, 2)),
+            _ => panic!("Expected a parse error"),
+        }
+        assert!(chunks.next().is_none());
+
+        // Reading at once also infers from the sample
+        assert!(Dataframe::from_reader(input.as_bytes(), &options).is_err());
+        let dataframe = Dataframe::from_reader(input.as_bytes(), &CsvReadOptions::new()).unwrap();
+        assert_eq!(
+            dataframe.get_column_type("value").unwrap(),
+            ColumnType::Text
+        );
+    }
+
+    #[test]
+    fn test_chunks_empty_sample_column() {
+        // The comment column is empty in the sample, and has text after it
+        let input = "id,comment\n1,\n2,NA\n3,late text\n4,\n";
+        let options = CsvReadOptions::new()
+            .with_null_tokens(&["NA"])
+            .with_infer_rows(2);
+        let chunks = Dataframe::chunks_from_reader(input.as_bytes(), &options, 2).unwrap();
+        assert_eq!(
+            chunks.column_types(),
+            &[ColumnType::Integer, ColumnType::Text]
+        );
+
+        let chunks: Vec<Dataframe> = chunks.collect::<Result<_, _>>().unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].at_str("comment", 1).unwrap().is_none());
+        assert_eq!(
+            chunks[1].at_str("comment", 0).unwrap(),
+            Some("late text".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read_empty_column() {
+        // Reading every record keeps a column without values as integers
+        let input = "id;comment\n1;\n2;\n";
+        let options = CsvReadOptions::new().with_delimiter(';');
+        let mut dataframe = Dataframe::from_reader(input.as_bytes(), &options).unwrap();
+        assert_eq!(
+            dataframe.get_column_type("comment").unwrap(),
+            ColumnType::Integer
+        );
+        assert!(dataframe.at_str("comment", 0).unwrap().is_none());
+        dataframe.drop_column("id").unwrap();
+        assert!(dataframe.has_rows());
+        assert!(!dataframe.has_records());
+
+        // A sample that misses the later values infers text
+        let input = "id;comment\n1;\n2;late text\n";
+        let options = options.with_infer_rows(1);
+        let dataframe = Dataframe::from_reader(input.as_bytes(), &options).unwrap();
+        assert_eq!(
+            dataframe.get_column_type("comment").unwrap(),
+            ColumnType::Text
+        );
+        assert_eq!(
+            dataframe.at_str("comment", 1).unwrap(),
+            Some("late text".to_string())
+        );
     }
 }
@@ -0,0 +1,436 @@
+#[cfg(test)]
+mod tests {
+    use rustic_ml::data_utils::{
+        decomposition::{Cholesky, LuDecomposition, QrDecomposition, Svd, SymmetricEigen},
+        matrix::{Matrix, MatrixError},
+    };
+
+    /// Assert that all values in the two slices are within the given tolerance
+    fn assert_all_close(actual: &[f32], expected: &[f32], tolerance: f32) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!(
+                (a - e).abs() <= tolerance,
+                "Expected {expected:?}, but got {actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_lu_factors() {
+        let matrix = Matrix::from_vec(3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+        let lu = LuDecomposition::new(&matrix).unwrap();
+
+        // L should be unit lower triangular, U upper triangular
+        let l = lu.l();
+        let u = lu.u();
+        for row in 0..3 {
+            assert_eq!(l.get(row, row), Some(&1.0));
+            for col in (row + 1)..3 {
+                assert_eq!(l.get(row, col), Some(&0.0));
+                assert_eq!(u.get(col, row), Some(&0.0));
+            }
+        }
+
+        // PA = LU
+        let pa = lu.p().multiply(&matrix).unwrap();
+        let product = l.multiply(&u).unwrap();
+        assert_all_close(&product.data, &pa.data, 1e-5);
+    }
+
+    #[test]
+    fn test_lu_pivoting() {
+        // The first pivot is zero, so rows must be swapped
+        let matrix = Matrix::from_vec(2, vec![0.0, 1.0, 1.0, 0.0]);
+        let lu = LuDecomposition::new(&matrix).unwrap();
+
+        assert_eq!(lu.permutation(), &[1, 0]);
+        assert!(!lu.is_singular());
+        assert_eq!(lu.determinant(), -1.0);
+    }
+
+    #[test]
+    fn test_lu_not_square() {
+        let matrix = Matrix::new(2, 3);
+        match LuDecomposition::new(&matrix) {
+            Err(err) => assert_eq!(err, MatrixError::NotSquare(String::from("2x3"))),
+            Ok(_) => panic!("Expected error for a non-square matrix"),
+        }
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        // 2x + y + z = 5, 4x - 6y = -2, -2x + 7y + 2z = 9 => x = 1, y = 1, z = 2
+        let matrix = Matrix::from_vec(3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+        let b = Matrix::from_vec(1, vec![5.0, -2.0, 9.0]);
+
+        let lu = LuDecomposition::new(&matrix).unwrap();
+        let x = lu.solve(&b).unwrap();
+
+        assert_eq!(x.shape(), "3x1");
+        assert_all_close(&x.data, &[1.0, 1.0, 2.0], 1e-5);
+    }
+
+    #[test]
+    fn test_lu_solve_multiple_rhs() {
+        let matrix = Matrix::from_vec(2, vec![4.0, 3.0, 6.0, 3.0]);
+        let b = Matrix::from_vec(2, vec![10.0, 7.0, 12.0, 9.0]);
+
+        let x = matrix.lu().unwrap().solve(&b).unwrap();
+
+        // Check that AX = B
+        let ax = matrix.multiply(&x).unwrap();
+        assert_all_close(&ax.data, &b.data, 1e-5);
+    }
+
+    #[test]
+    fn test_lu_solve_errors() {
+        let singular = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        let lu = LuDecomposition::new(&singular).unwrap();
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.0);
+
+        match lu.solve(&Matrix::from_vec(1, vec![1.0, 2.0])) {
+            Err(err) => assert_eq!(err, MatrixError::SingularMatrix),
+            Ok(_) => panic!("Expected error for a singular matrix"),
+        }
+
+        let lu = LuDecomposition::new(&Matrix::identity(2)).unwrap();
+        match lu.solve(&Matrix::new(3, 1)) {
+            Err(MatrixError::ShapeMismatch { .. }) => (), // Success
+            _ => panic!("Expected shape mismatch error"),
+        }
+    }
+
+    #[test]
+    fn test_lu_inverse() {
+        let matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0]);
+        let inverse = LuDecomposition::new(&matrix).unwrap().inverse().unwrap();
+
+        let expected = vec![-24.0, 18.0, 5.0, 20.0, -15.0, -4.0, -5.0, 4.0, 1.0];
+        assert_all_close(&inverse.data, &expected, 1e-4);
+    }
+
+    #[test]
+    fn test_qr_factors() {
+        // 4x3 matrix
+        let matrix = Matrix::from_vec(
+            3,
+            vec![
+                12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0, 1.0, 2.0, 3.0,
+            ],
+        );
+        let qr = QrDecomposition::new(&matrix);
+
+        let q = qr.q();
+        let r = qr.r();
+        assert_eq!(q.shape(), "4x3");
+        assert_eq!(r.shape(), "3x3");
+
+        // R is upper triangular
+        for row in 0..3 {
+            for col in 0..row {
+                assert_eq!(r.get(row, col), Some(&0.0));
+            }
+        }
+
+        // Q has orthonormal columns
+        let qtq = q.get_transposed().multiply(&q).unwrap();
+        assert_all_close(&qtq.data, &Matrix::identity(3).data, 1e-5);
+
+        // A = QR
+        let product = q.multiply(&r).unwrap();
+        assert_all_close(&product.data, &matrix.data, 1e-3);
+    }
+
+    #[test]
+    fn test_qr_rank() {
+        let full_rank = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 7.0]);
+        assert_eq!(QrDecomposition::new(&full_rank).rank(), 2);
+        assert!(QrDecomposition::new(&full_rank).is_full_rank());
+
+        // Second column is two times the first column
+        let deficient = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        assert_eq!(QrDecomposition::new(&deficient).rank(), 1);
+        assert!(!QrDecomposition::new(&deficient).is_full_rank());
+    }
+
+    #[test]
+    fn test_qr_solve_errors() {
+        let deficient = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        let qr = QrDecomposition::new(&deficient);
+
+        match qr.solve(&Matrix::new(3, 1)) {
+            Err(err) => assert_eq!(
+                err,
+                MatrixError::RankDeficient {
+                    rank: 1,
+                    required_rank: 2
+                }
+            ),
+            Ok(_) => panic!("Expected rank deficient error"),
+        }
+
+        match qr.solve(&Matrix::new(2, 1)) {
+            Err(MatrixError::ShapeMismatch { .. }) => (), // Success
+            _ => panic!("Expected shape mismatch error"),
+        }
+    }
+
+    #[test]
+    fn test_cholesky_factor() {
+        let matrix = Matrix::from_vec(
+            3,
+            vec![4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
+        );
+        let cholesky = Cholesky::new(&matrix).unwrap();
+
+        let l = cholesky.l();
+        assert_all_close(
+            &l.data,
+            &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0],
+            1e-5,
+        );
+
+        // A = LL^T
+        let product = l.multiply(&l.get_transposed()).unwrap();
+        assert_all_close(&product.data, &matrix.data, 1e-4);
+
+        assert!((cholesky.determinant() - 36.0).abs() < 1e-3);
+        assert!((cholesky.log_determinant() - 36.0_f32.ln()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cholesky_solve_and_inverse() {
+        let matrix = Matrix::from_vec(2, vec![4.0, 2.0, 2.0, 3.0]);
+        let cholesky = matrix.cholesky().unwrap();
+
+        let b = Matrix::from_vec(1, vec![2.0, 1.0]);
+        let x = cholesky.solve(&b).unwrap();
+        assert_all_close(&x.data, &[0.5, 0.0], 1e-5);
+
+        let inverse = cholesky.inverse();
+        assert_all_close(&inverse.data, &[0.375, -0.25, -0.25, 0.5], 1e-5);
+
+        match cholesky.solve(&Matrix::new(3, 1)) {
+            Err(MatrixError::ShapeMismatch { .. }) => (), // Success
+            _ => panic!("Expected shape mismatch error"),
+        }
+    }
+
+    #[test]
+    fn test_cholesky_errors() {
+        let not_square = Matrix::new(2, 3);
+        assert!(matches!(
+            Cholesky::new(&not_square),
+            Err(MatrixError::NotSquare(_))
+        ));
+
+        let not_symmetric = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert!(matches!(
+            Cholesky::new(&not_symmetric),
+            Err(MatrixError::NotSymmetric)
+        ));
+
+        let not_positive_definite = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 1.0]);
+        assert!(matches!(
+            Cholesky::new(&not_positive_definite),
+            Err(MatrixError::NotPositiveDefinite)
+        ));
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let matrix = Matrix::from_vec(3, vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+        let eigen = SymmetricEigen::new(&matrix).unwrap();
+
+        // Eigenvalues are 2 + sqrt(2), 2 and 2 - sqrt(2), from largest to smallest
+        let sqrt_2 = 2.0_f32.sqrt();
+        assert_all_close(
+            eigen.eigenvalues(),
+            &[2.0 + sqrt_2, 2.0, 2.0 - sqrt_2],
+            1e-5,
+        );
+
+        // Eigenvectors are orthonormal
+        let v = eigen.eigenvectors();
+        assert!(v.is_orthogonal());
+
+        // AV = VΛ
+        let av = matrix.multiply(&v).unwrap();
+        let v_lambda = v.multiply(&eigen.eigenvalue_matrix()).unwrap();
+        assert_all_close(&av.data, &v_lambda.data, 1e-5);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_diagonal() {
+        let matrix = Matrix::from_vec(3, vec![1.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 2.0]);
+        let eigen = matrix.symmetric_eigen().unwrap();
+
+        assert_eq!(eigen.eigenvalues(), &[3.0, 2.0, 1.0]);
+        assert_eq!(
+            eigen.eigenvectors().data,
+            vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_symmetric_eigen_errors() {
+        let not_symmetric = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert!(matches!(
+            SymmetricEigen::new(&not_symmetric),
+            Err(MatrixError::NotSymmetric)
+        ));
+
+        let not_square = Matrix::new(3, 2);
+        assert!(matches!(
+            SymmetricEigen::new(&not_square),
+            Err(MatrixError::NotSquare(_))
+        ));
+    }
+
+    /// Multiply the three SVD factors back together
+    fn reconstruct(svd: &Svd) -> Matrix {
+        svd.u()
+            .multiply(&svd.sigma())
+            .unwrap()
+            .multiply(&svd.v_t())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_svd_thin_tall() {
+        // 4x2 matrix
+        let matrix = Matrix::from_vec(2, vec![2.0, 0.0, 1.0, 1.0, 0.0, 2.0, 1.0, -1.0]);
+        let svd = Svd::new(&matrix).unwrap();
+
+        assert_eq!(svd.u().shape(), "4x2");
+        assert_eq!(svd.sigma().shape(), "2x2");
+        assert_eq!(svd.v_t().shape(), "2x2");
+
+        // Singular values are sorted and non-negative
+        let values = svd.singular_values();
+        assert!(values[0] >= values[1] && values[1] >= 0.0);
+
+        // Singular values are the square roots of the eigenvalues of A^T A
+        let ata = matrix.get_transposed().multiply(&matrix).unwrap();
+        let eigenvalues = ata.symmetric_eigen().unwrap();
+        let expected: Vec<f32> = eigenvalues.eigenvalues().iter().map(|x| x.sqrt()).collect();
+        assert_all_close(values, &expected, 1e-5);
+
+        // U has orthonormal columns and A = UΣV^T
+        let utu = svd.u().get_transposed().multiply(&svd.u()).unwrap();
+        assert_all_close(&utu.data, &Matrix::identity(2).data, 1e-5);
+        assert!(svd.v_t().is_orthogonal());
+        assert_all_close(&reconstruct(&svd).data, &matrix.data, 1e-5);
+    }
+
+    #[test]
+    fn test_svd_thin_wide() {
+        // 2x3 matrix
+        let matrix = Matrix::from_vec(3, vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0]);
+        let svd = matrix.svd().unwrap();
+
+        assert_eq!(svd.u().shape(), "2x2");
+        assert_eq!(svd.sigma().shape(), "2x2");
+        assert_eq!(svd.v_t().shape(), "2x3");
+        assert_all_close(svd.singular_values(), &[5.0, 3.0], 1e-5);
+        assert_all_close(&reconstruct(&svd).data, &matrix.data, 1e-5);
+    }
+
+    #[test]
+    fn test_svd_full() {
+        let matrix = Matrix::from_vec(3, vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0]);
+        let svd = Svd::full(&matrix).unwrap();
+
+        assert_eq!(svd.u().shape(), "2x2");
+        assert_eq!(svd.sigma().shape(), "2x3");
+        assert_eq!(svd.v_t().shape(), "3x3");
+        assert!(svd.u().is_orthogonal());
+        assert!(svd.v_t().is_orthogonal());
+        assert_all_close(&reconstruct(&svd).data, &matrix.data, 1e-5);
+
+        // Tall matrix, with a singular value of zero
+        let matrix = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        let svd = matrix.svd_full().unwrap();
+        assert_eq!(svd.u().shape(), "3x3");
+        assert_eq!(svd.sigma().shape(), "3x2");
+        assert!(svd.u().is_orthogonal());
+        assert_all_close(&reconstruct(&svd).data, &matrix.data, 1e-5);
+    }
+
+    #[test]
+    fn test_svd_rank_and_condition_number() {
+        let matrix = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        let svd = Svd::new(&matrix).unwrap();
+        assert_eq!(svd.rank(), 1);
+        assert_eq!(svd.condition_number(), f32::INFINITY);
+
+        let matrix = Matrix::from_vec(2, vec![4.0, 0.0, 0.0, 2.0]);
+        let svd = Svd::new(&matrix).unwrap();
+        assert_eq!(svd.rank(), 2);
+        assert!((svd.condition_number() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_svd_pseudo_inverse() {
+        // For a matrix with full column rank, pinv(A) = (A^T A)^-1 A^T
+        let matrix = Matrix::from_vec(2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0]);
+        let pinv = Svd::new(&matrix).unwrap().pseudo_inverse();
+        assert_eq!(pinv.shape(), "2x3");
+
+        let at = matrix.get_transposed();
+        let expected = at
+            .multiply(&matrix)
+            .unwrap()
+            .get_inverse()
+            .unwrap()
+            .multiply(&at)
+            .unwrap();
+        assert_all_close(&pinv.data, &expected.data, 1e-5);
+
+        // A * pinv(A) * A = A, also for a rank deficient matrix
+        let matrix = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        let pinv = Svd::new(&matrix).unwrap().pseudo_inverse();
+        let product = matrix.multiply(&pinv).unwrap().multiply(&matrix).unwrap();
+        assert_all_close(&product.data, &matrix.data, 1e-5);
+    }
+
+    #[test]
+    fn test_svd_low_rank_approximation() {
+        let matrix = Matrix::from_vec(3, vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0]);
+        let svd = Svd::new(&matrix).unwrap();
+
+        // Keeping all singular values gives back the matrix
+        let full_rank = svd.low_rank_approximation(2).unwrap();
+        assert_all_close(&full_rank.data, &matrix.data, 1e-5);
+
+        // Rank one approximation is 5 * u1 * v1^T
+        let rank_one = svd.low_rank_approximation(1).unwrap();
+        assert_eq!(rank_one.shape(), "2x3");
+        assert_all_close(&rank_one.data, &[2.5, 2.5, 0.0, 2.5, 2.5, 0.0], 1e-5);
+
+        assert_eq!(svd.low_rank_approximation(0).unwrap().data, vec![0.0; 6]);
+        assert!(matches!(
+            svd.low_rank_approximation(3),
+            Err(MatrixError::IllegalRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_lu_solve_f64_precision() {
+        // The Hilbert matrix is badly conditioned, so solving it needs the precision of f64
+        let order = 6;
+        let data: Vec<f64> = (0..order * order)
+            .map(|i| 1.0 / ((i / order + i % order + 1) as f64))
+            .collect();
+        let hilbert = Matrix::from_vec_typed(order, data);
+        let expected = Matrix::from_vec_typed(1, vec![1.0; order]);
+        let rhs = hilbert.multiply(&expected).unwrap();
+
+        let solution = LuDecomposition::new(&hilbert).unwrap().solve(&rhs).unwrap();
+        for x in solution.data.iter() {
+            assert!((x - 1.0).abs() < 1e-6, "Expected 1.0, but got {x}");
+        }
+    }
+}
@@ -2,7 +2,8 @@
 mod tests {
     use std::{ops::RangeInclusive, vec};
 
-    use rustic_ml::data_utils::matrix::{Matrix, MatrixError};
+    use rand::{rngs::StdRng, SeedableRng};
+    use rustic_ml::data_utils::matrix::{Axis, Matrix, MatrixError, Norm};
 
     #[test]
     fn test_new_matrix_constructor() {
@@ -951,6 +952,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_submatrix_non_square() {
+        // 2x4 matrix, rows must be indexed with the amount of columns
+        let matrix = Matrix::from_vec(4, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+
+        let sub = matrix.submatrix(0..2, 1..3).unwrap();
+        assert_eq!(sub.data, vec![2.0, 3.0, 6.0, 7.0]);
+        assert_eq!(sub.shape(), "2x2");
+
+        // 3x2 matrix
+        let matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let sub = matrix.submatrix(1..3, 0..2).unwrap();
+        assert_eq!(sub.data, vec![3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(sub.shape(), "2x2");
+    }
+
+    #[test]
+    fn test_submatrix_as_slice() {
+        let matrix = Matrix::from_vec(4, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+
+        let view = matrix.submatrix_as_slice(0..2, 1..3).unwrap();
+        assert_eq!(view.shape(), "2x2");
+        assert_eq!(
+            view.to_matrix().data,
+            matrix.submatrix(0..2, 1..3).unwrap().data
+        );
+
+        match matrix.submatrix_as_slice(0..3, 0..2) {
+            Err(MatrixError::IllegalRange(_)) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+    }
+
     #[test]
     fn test_multiply_matrix_positive_1() {
         // Testing on example from Wikipedia:
@@ -1026,6 +1061,52 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_multiply_matches_naive() {
+        // Sizes that do not divide the tile size, and large enough to use several threads
+        let mat_a = Matrix::with_rand_range(150, 131, -1.0..=1.0);
+        let mat_b = Matrix::with_rand_range(131, 97, -1.0..=1.0);
+
+        let expected = mat_a.multiply_naive(&mat_b).unwrap();
+        let result = mat_a.multiply(&mat_b).unwrap();
+
+        assert_eq!(result.shape(), "150x97");
+        for (x, y) in result.data.iter().zip(expected.data.iter()) {
+            assert!((x - y).abs() < 1e-4, "{x} != {y}");
+        }
+    }
+
+    #[test]
+    fn test_multiply_with_threads() {
+        let mat_a = Matrix::with_rand_range(37, 20, -1.0..=1.0);
+        let mat_b = Matrix::with_rand_range(20, 5, -1.0..=1.0);
+        let expected = mat_a.multiply_naive(&mat_b).unwrap();
+
+        // Zero threads are treated as one, and more threads than rows are allowed
+        for threads in [0, 1, 2, 3, 8, 64] {
+            let result = mat_a.multiply_with_threads(&mat_b, threads).unwrap();
+            assert_eq!(result.shape(), "37x5");
+            for (x, y) in result.data.iter().zip(expected.data.iter()) {
+                assert!((x - y).abs() < 1e-5, "{x} != {y} with {threads} threads");
+            }
+        }
+
+        match mat_b.multiply_with_threads(&mat_a, 4) {
+            Err(MatrixError::MatrixMultiply) => (), // Success
+            _ => panic!("Expected error"),
+        }
+    }
+
+    #[test]
+    fn test_multiply_empty_inner_dimension() {
+        let mat_a = Matrix::new(3, 0);
+        let mat_b = Matrix::new(0, 2);
+
+        let result = mat_a.multiply(&mat_b).unwrap();
+        assert_eq!(result.shape(), "3x2");
+        assert_eq!(result.data, vec![0.0; 6]);
+    }
+
     #[test]
     fn test_matrix_is_vector() {
         let data: Vec<f32> = vec![0.0; 5];
@@ -1101,4 +1182,1111 @@ mod tests {
 
         assert_eq!(matrix.data, vec![1.0; 9]);
     }
+
+    #[test]
+    fn test_get_transposed_matrix() {
+        // 2x3 matrix
+        let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let matrix = Matrix::from_vec(3, data);
+
+        let transposed = matrix.get_transposed();
+        assert_eq!(transposed.rows, 3);
+        assert_eq!(transposed.cols, 2);
+        assert_eq!(transposed.data, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+
+        // Original matrix should not be changed
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(matrix.shape(), "2x3");
+    }
+
+    #[test]
+    fn test_transpose_square_matrix() {
+        let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let mut matrix = Matrix::from_vec(3, data);
+
+        matrix.transpose();
+        assert_eq!(matrix.shape(), "3x3");
+        assert_eq!(
+            matrix.data,
+            vec![1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
+        );
+    }
+
+    #[test]
+    fn test_transpose_non_square_matrix() {
+        // 2x4 matrix
+        let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let mut matrix = Matrix::from_vec(4, data);
+
+        matrix.transpose();
+        assert_eq!(matrix.rows, 4);
+        assert_eq!(matrix.cols, 2);
+        assert_eq!(matrix.data, vec![1.0, 5.0, 2.0, 6.0, 3.0, 7.0, 4.0, 8.0]);
+
+        // Transposing twice gives the original matrix
+        matrix.transpose();
+        assert_eq!(matrix.shape(), "2x4");
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn test_transpose_vector() {
+        let mut row_vector = Matrix::from_vec(5, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        row_vector.transpose();
+        assert_eq!(row_vector.rows, 5);
+        assert_eq!(row_vector.cols, 1);
+        assert_eq!(row_vector.data, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_transpose_large_matrices() {
+        // Sizes that are larger than, and not a multiple of, the tile size
+        for (rows, cols) in [(70, 70), (70, 45), (33, 100)] {
+            let data: Vec<f32> = (0..rows * cols).map(|x| x as f32).collect();
+            let matrix = Matrix::from_vec(cols, data);
+
+            let transposed = matrix.get_transposed();
+            let mut in_place = Matrix::from_vec(cols, matrix.data.clone());
+            in_place.transpose();
+
+            assert_eq!(transposed.shape(), format!("{cols}x{rows}"));
+            assert_eq!(in_place.shape(), format!("{cols}x{rows}"));
+            assert_eq!(in_place.data, transposed.data);
+
+            for row in 0..rows {
+                for col in 0..cols {
+                    assert_eq!(matrix.get(row, col), transposed.get(col, row));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_determinant() {
+        let matrix = Matrix::from_vec(3, vec![6.0, 1.0, 1.0, 4.0, -2.0, 5.0, 2.0, 8.0, 7.0]);
+        let det = matrix.determinant().unwrap();
+        assert!((det - -306.0).abs() < 1e-3, "Determinant was {det}");
+
+        let matrix = Matrix::from_vec(
+            4,
+            vec![
+                1.0, 0.0, 2.0, -1.0, 3.0, 0.0, 0.0, 5.0, 2.0, 1.0, 4.0, -3.0, 1.0, 0.0, 5.0, 0.0,
+            ],
+        );
+        let det = matrix.determinant().unwrap();
+        assert!((det - 30.0).abs() < 1e-3, "Determinant was {det}");
+
+        // Singular matrix
+        let matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert!(matrix.determinant().unwrap().abs() < 1e-4);
+
+        match Matrix::new(2, 3).determinant() {
+            Err(err) => assert_eq!(err, MatrixError::NotSquare(String::from("2x3"))),
+            Ok(_) => panic!("Expected error"),
+        }
+    }
+
+    #[test]
+    fn test_matrix_det_2x2_and_3x3() {
+        let matrix = Matrix::from_vec(2, vec![3.0, 8.0, 4.0, 6.0]);
+        assert_eq!(matrix.det_2x2(), Ok(-14.0));
+        assert!(matrix.det_3x3().is_err());
+
+        let matrix = Matrix::from_vec(3, vec![6.0, 1.0, 1.0, 4.0, -2.0, 5.0, 2.0, 8.0, 7.0]);
+        assert_eq!(matrix.det_3x3(), Ok(-306.0));
+        assert!(matrix.det_2x2().is_err());
+    }
+
+    #[test]
+    fn test_matrix_get_inverse_positive() {
+        let matrix = Matrix::from_vec(2, vec![4.0, 7.0, 2.0, 6.0]);
+        let inverse = matrix.get_inverse().unwrap();
+
+        let expected = [0.6, -0.7, -0.2, 0.4];
+        for (value, expected) in inverse.data.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() < 1e-5);
+        }
+
+        // A * A^-1 = I
+        let product = matrix.multiply(&inverse).unwrap();
+        let identity = Matrix::identity(2);
+        for (value, expected) in product.data.iter().zip(identity.data.iter()) {
+            assert!((value - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_matrix_get_inverse_negative() {
+        let singular = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        match singular.get_inverse() {
+            Err(err) => assert_eq!(err, MatrixError::SingularMatrix),
+            Ok(_) => panic!("Singular matrix should not have an inverse"),
+        }
+
+        let non_square = Matrix::new(3, 2);
+        match non_square.get_inverse() {
+            Err(err) => assert_eq!(err, MatrixError::NotSquare(String::from("3x2"))),
+            Ok(_) => panic!("Non-square matrix should not have an inverse"),
+        }
+    }
+
+    #[test]
+    fn test_matrix_inverse() {
+        let mut matrix = Matrix::from_vec(2, vec![2.0, 0.0, 0.0, 4.0]);
+        matrix.inverse().unwrap();
+        assert_eq!(matrix.data, vec![0.5, 0.0, 0.0, 0.25]);
+
+        // Matrix should not change on error
+        let mut singular = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(singular.inverse().is_err());
+        assert_eq!(singular.data, vec![1.0, 2.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_matrix_solve_positive() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27 => x = 5, y = 3, z = -2
+        let matrix = Matrix::from_vec(3, vec![1.0, 1.0, 1.0, 0.0, 2.0, 5.0, 2.0, 5.0, -1.0]);
+        let rhs = Matrix::from_vec(1, vec![6.0, -4.0, 27.0]);
+
+        let solution = matrix.solve(&rhs).unwrap();
+        assert_eq!(solution.shape(), "3x1");
+        for (value, expected) in solution.data.iter().zip([5.0, 3.0, -2.0].iter()) {
+            assert!((value - expected).abs() < 1e-4, "Got {:?}", solution.data);
+        }
+
+        // Several right-hand sides at once
+        let rhs = Matrix::from_vec(2, vec![6.0, 1.0, -4.0, 0.0, 27.0, 2.0]);
+        let solution = matrix.solve(&rhs).unwrap();
+        assert_eq!(solution.shape(), "3x2");
+        let product = matrix.multiply(&solution).unwrap();
+        for (value, expected) in product.data.iter().zip(rhs.data.iter()) {
+            assert!((value - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_matrix_solve_negative() {
+        match Matrix::new(3, 2).solve(&Matrix::new(3, 1)) {
+            Err(err) => assert_eq!(err, MatrixError::NotSquare(String::from("3x2"))),
+            Ok(_) => panic!("Expected error"),
+        }
+
+        match Matrix::identity(3).solve(&Matrix::new(2, 1)) {
+            Err(MatrixError::ShapeMismatch { .. }) => (), // Success
+            _ => panic!("Expected shape mismatch error"),
+        }
+
+        let singular = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        match singular.solve(&Matrix::new(2, 1)) {
+            Err(MatrixError::RankDeficient { rank, .. }) => assert_eq!(rank, 1),
+            _ => panic!("Expected rank deficient error"),
+        }
+    }
+
+    #[test]
+    fn test_matrix_lstsq() {
+        // Fit the line y = 1 + 2x to points that lie exactly on it
+        let design = Matrix::from_vec(2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+        let targets = Matrix::from_vec(1, vec![1.0, 3.0, 5.0, 7.0]);
+
+        let coefficients = design.lstsq(&targets).unwrap();
+        assert_eq!(coefficients.shape(), "2x1");
+        assert!((coefficients.data[0] - 1.0).abs() < 1e-4);
+        assert!((coefficients.data[1] - 2.0).abs() < 1e-4);
+
+        // Noisy points, compared with the solution of the normal equations
+        let targets = Matrix::from_vec(1, vec![1.0, 2.0, 2.0, 4.0]);
+        let coefficients = design.lstsq(&targets).unwrap();
+        assert!((coefficients.data[0] - 0.9).abs() < 1e-4);
+        assert!((coefficients.data[1] - 0.9).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_matrix_lstsq_rank_deficient() {
+        let design = Matrix::from_vec(2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        match design.lstsq(&Matrix::new(3, 1)) {
+            Err(err) => assert_eq!(
+                err,
+                MatrixError::RankDeficient {
+                    rank: 1,
+                    required_rank: 2
+                }
+            ),
+            Ok(_) => panic!("Expected rank deficient error"),
+        }
+
+        // Underdetermined system can not have full column rank
+        let wide = Matrix::from_vec(3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+        assert!(wide.lstsq(&Matrix::new(2, 1)).is_err());
+    }
+
+    #[test]
+    fn test_matrix_is_orthogonal() {
+        assert!(Matrix::identity(4).is_orthogonal());
+
+        // Rotation matrix
+        let angle: f32 = 0.3;
+        let rotation =
+            Matrix::from_vec(2, vec![angle.cos(), -angle.sin(), angle.sin(), angle.cos()]);
+        assert!(rotation.is_orthogonal());
+
+        // Permutation matrix
+        let permutation = Matrix::from_vec(3, vec![0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+        assert!(permutation.is_orthogonal());
+
+        // Q from a QR decomposition of a square matrix
+        let matrix = Matrix::from_vec(3, vec![2.0, -1.0, 0.0, 1.0, 3.0, 2.0, 0.0, 1.0, 4.0]);
+        assert!(matrix.qr().q().is_orthogonal());
+
+        // Not orthogonal
+        assert!(!matrix.is_orthogonal());
+        assert!(!Matrix::from_vec(2, vec![2.0, 0.0, 0.0, 2.0]).is_orthogonal());
+        assert!(!Matrix::new(2, 3).is_orthogonal());
+    }
+
+    #[test]
+    fn test_matrix_rank() {
+        assert_eq!(Matrix::identity(3).rank(), Ok(3));
+        assert_eq!(Matrix::new(3, 3).rank(), Ok(0));
+
+        let matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(matrix.rank(), Ok(2));
+
+        let wide = Matrix::from_vec(4, vec![1.0, 0.0, 2.0, 0.0, 2.0, 0.0, 4.0, 0.0]);
+        assert_eq!(wide.rank(), Ok(1));
+    }
+
+    #[test]
+    fn test_matrix_condition_number() {
+        let matrix = Matrix::from_vec(2, vec![10.0, 0.0, 0.0, 0.1]);
+        let cond = matrix.condition_number().unwrap();
+        assert!((cond - 100.0).abs() < 1e-3, "Condition number was {cond}");
+
+        assert_eq!(Matrix::identity(3).condition_number(), Ok(1.0));
+    }
+
+    #[test]
+    fn test_matrix_pseudo_inverse() {
+        // Pseudo-inverse of an invertible matrix is the inverse
+        let matrix = Matrix::from_vec(2, vec![4.0, 7.0, 2.0, 6.0]);
+        let pinv = matrix.pseudo_inverse().unwrap();
+        let inverse = matrix.get_inverse().unwrap();
+        for (value, expected) in pinv.data.iter().zip(inverse.data.iter()) {
+            assert!((value - expected).abs() < 1e-5);
+        }
+
+        // Pseudo-inverse of a singular matrix exists
+        let singular = Matrix::from_vec(2, vec![1.0, 1.0, 1.0, 1.0]);
+        let pinv = singular.pseudo_inverse().unwrap();
+        for value in pinv.data.iter() {
+            assert!((value - 0.25).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_matrix_low_rank_approximation() {
+        let matrix = Matrix::from_vec(2, vec![3.0, 0.0, 0.0, 1.0]);
+        let approximation = matrix.low_rank_approximation(1).unwrap();
+        assert_eq!(approximation.shape(), "2x2");
+        for (value, expected) in approximation.data.iter().zip([3.0, 0.0, 0.0, 0.0].iter()) {
+            assert!((value - expected).abs() < 1e-6);
+        }
+
+        assert!(matrix.low_rank_approximation(3).is_err());
+    }
+
+    #[test]
+    fn test_matrix_elementwise_positive() {
+        let a = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::from_vec(2, vec![2.0, 4.0, 6.0, 8.0]);
+
+        assert_eq!(
+            a.add_elementwise(&b).unwrap().data,
+            vec![3.0, 6.0, 9.0, 12.0]
+        );
+        assert_eq!(
+            a.sub_elementwise(&b).unwrap().data,
+            vec![-1.0, -2.0, -3.0, -4.0]
+        );
+        assert_eq!(
+            a.mul_elementwise(&b).unwrap().data,
+            vec![2.0, 8.0, 18.0, 32.0]
+        );
+        assert_eq!(b.div_elementwise(&a).unwrap().data, vec![2.0; 4]);
+    }
+
+    #[test]
+    fn test_matrix_elementwise_negative() {
+        let a = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::from_vec(4, vec![1.0, 2.0, 3.0, 4.0]);
+
+        let expected = MatrixError::ShapeMismatch {
+            first_matrix_shape: String::from("2x2"),
+            second_matrix_shape: String::from("1x4"),
+        };
+        assert_eq!(a.add_elementwise(&b).err(), Some(expected));
+        assert!(a.sub_elementwise(&b).is_err());
+        assert!(a.mul_elementwise(&b).is_err());
+        assert!(a.div_elementwise(&b).is_err());
+
+        let zeros = Matrix::new(2, 2);
+        assert_eq!(
+            a.div_elementwise(&zeros).err(),
+            Some(MatrixError::DivideByZero)
+        );
+    }
+
+    #[test]
+    fn test_matrix_add_sub_operators() {
+        let a = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::from_vec(2, vec![4.0, 3.0, 2.0, 1.0]);
+
+        assert_eq!((&a + &b).data, vec![5.0; 4]);
+        assert_eq!((&a - &b).data, vec![-3.0, -1.0, 1.0, 3.0]);
+        assert_eq!((a.clone() + &b).data, vec![5.0; 4]);
+        assert_eq!((a.clone() - b.clone()).data, vec![-3.0, -1.0, 1.0, 3.0]);
+
+        // Expression with several operators
+        let result = &(&a + &b) - &a;
+        assert_eq!(result.data, b.data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_add_operator_shape_mismatch() {
+        let a = Matrix::new(2, 2);
+        let b = Matrix::new(2, 3);
+        let _ = &a + &b;
+    }
+
+    #[test]
+    fn test_matrix_scalar_operators() {
+        let a = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+
+        assert_eq!((&a * 2.0).data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((2.0 * &a).data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((&a / 2.0).data, vec![0.5, 1.0, 1.5, 2.0]);
+        assert_eq!((-&a).data, vec![-1.0, -2.0, -3.0, -4.0]);
+        assert_eq!((-a.clone() * 3.0).data, vec![-3.0, -6.0, -9.0, -12.0]);
+
+        // The original matrix is not changed by the reference operators
+        assert_eq!(a.data, vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_matrix_assign_operators() {
+        let mut a = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::from_vec(2, vec![1.0; 4]);
+
+        a += &b;
+        assert_eq!(a.data, vec![2.0, 3.0, 4.0, 5.0]);
+
+        a -= &b;
+        assert_eq!(a.data, vec![1.0, 2.0, 3.0, 4.0]);
+
+        a *= 2.0;
+        assert_eq!(a.data, vec![2.0, 4.0, 6.0, 8.0]);
+
+        a /= 4.0;
+        assert_eq!(a.data, vec![0.5, 1.0, 1.5, 2.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_add_assign_shape_mismatch() {
+        let mut a = Matrix::new(2, 2);
+        a += &Matrix::new(3, 2);
+    }
+
+    #[test]
+    fn test_matrix_broadcast_row_vector() {
+        // 3x2 matrix and a 1x2 bias row
+        let matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bias = Matrix::from_vec(2, vec![10.0, 20.0]);
+
+        let result = matrix.broadcast_add(&bias).unwrap();
+        assert_eq!(result.shape(), "3x2");
+        assert_eq!(result.data, vec![11.0, 22.0, 13.0, 24.0, 15.0, 26.0]);
+
+        // Broadcasting works in both directions
+        let result = bias.broadcast_sub(&matrix).unwrap();
+        assert_eq!(result.data, vec![9.0, 18.0, 7.0, 16.0, 5.0, 14.0]);
+
+        // Centering the columns by their mean
+        let mean = Matrix::from_vec(2, vec![3.0, 4.0]);
+        let centered = matrix.broadcast_sub(&mean).unwrap();
+        assert_eq!(centered.data, vec![-2.0, -2.0, 0.0, 0.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_matrix_broadcast_column_vector() {
+        let matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let column = Matrix::from_vec(1, vec![2.0, 3.0]);
+
+        let result = matrix.broadcast_mul(&column).unwrap();
+        assert_eq!(result.shape(), "2x3");
+        assert_eq!(result.data, vec![2.0, 4.0, 6.0, 12.0, 15.0, 18.0]);
+
+        let result = matrix.broadcast_div(&column).unwrap();
+        assert_eq!(result.data, vec![0.5, 1.0, 1.5, 4.0 / 3.0, 5.0 / 3.0, 2.0]);
+    }
+
+    #[test]
+    fn test_matrix_broadcast_outer() {
+        // Column vector and row vector broadcast to a full matrix
+        let column = Matrix::from_vec(1, vec![1.0, 2.0, 3.0]);
+        let row = Matrix::from_vec(2, vec![10.0, 20.0]);
+
+        assert_eq!(column.broadcast_shape(&row), Ok((3, 2)));
+        let result = column.broadcast_add(&row).unwrap();
+        assert_eq!(result.data, vec![11.0, 21.0, 12.0, 22.0, 13.0, 23.0]);
+
+        // Same shapes behave as element-wise operations
+        let same = Matrix::from_vec(2, vec![1.0; 6]);
+        assert_eq!(
+            same.broadcast_add(&same).unwrap().data,
+            same.add_elementwise(&same).unwrap().data
+        );
+    }
+
+    #[test]
+    fn test_matrix_broadcast_negative() {
+        let matrix = Matrix::new(3, 2);
+        let row = Matrix::new(1, 3);
+
+        let expected = MatrixError::ShapeMismatch {
+            first_matrix_shape: String::from("3x2"),
+            second_matrix_shape: String::from("1x3"),
+        };
+        assert_eq!(matrix.broadcast_add(&row).err(), Some(expected));
+        assert!(matrix.broadcast_shape(&row).is_err());
+        assert!(matrix.broadcast_sub(&Matrix::new(2, 1)).is_err());
+        assert!(matrix.broadcast_mul(&Matrix::new(2, 2)).is_err());
+
+        let zeros = Matrix::new(1, 2);
+        assert_eq!(
+            matrix.broadcast_div(&zeros).err(),
+            Some(MatrixError::DivideByZero)
+        );
+    }
+
+    #[test]
+    fn test_matrix_index_tuple() {
+        let mut matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert_eq!(matrix[(0, 0)], 1.0);
+        assert_eq!(matrix[(0, 2)], 3.0);
+        assert_eq!(matrix[(1, 1)], 5.0);
+
+        matrix[(1, 2)] = 10.0;
+        matrix[(0, 0)] += 1.0;
+        assert_eq!(matrix.data, vec![2.0, 2.0, 3.0, 4.0, 5.0, 10.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_matrix_index_tuple_out_of_bounds() {
+        let matrix = Matrix::new(2, 3);
+        // Column out of bounds, even if the flat index is inside the data
+        let _ = matrix[(0, 3)];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_matrix_index_mut_tuple_out_of_bounds() {
+        let mut matrix = Matrix::new(2, 3);
+        matrix[(2, 0)] = 1.0;
+    }
+
+    #[test]
+    fn test_matrix_index_row() {
+        let mut matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert_eq!(&matrix[0], &[1.0, 2.0, 3.0]);
+        assert_eq!(&matrix[1], matrix.get_row_as_slice(1).unwrap());
+        assert_eq!(matrix[1][2], 6.0);
+
+        matrix[0][1] = 7.0;
+        matrix[1].copy_from_slice(&[0.0, 0.0, 0.0]);
+        assert_eq!(matrix.data, vec![1.0, 7.0, 3.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row index out of bounds")]
+    fn test_matrix_index_row_out_of_bounds() {
+        let matrix = Matrix::new(2, 3);
+        let _ = &matrix[2];
+    }
+
+    #[test]
+    fn test_matrix_sum_mean_trace() {
+        let matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+
+        assert_eq!(matrix.sum(), 45.0);
+        assert_eq!(matrix.mean(), 5.0);
+        assert_eq!(matrix.trace(), Ok(15.0));
+
+        match Matrix::new(2, 3).trace() {
+            Err(err) => assert_eq!(err, MatrixError::NotSquare(String::from("2x3"))),
+            Ok(_) => panic!("Expected error for a non-square matrix"),
+        }
+    }
+
+    #[test]
+    fn test_matrix_norm() {
+        let matrix = Matrix::from_vec(2, vec![1.0, -2.0, -3.0, 4.0]);
+
+        assert!((matrix.norm(Norm::Frobenius) - 30.0_f32.sqrt()).abs() < 1e-6);
+        assert_eq!(matrix.norm(Norm::L1), 6.0);
+        assert_eq!(matrix.norm(Norm::Infinity), 7.0);
+    }
+
+    #[test]
+    fn test_matrix_sum_and_mean_axis() {
+        // 2x3 matrix
+        let matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let column_sums = matrix.sum_axis(Axis::Rows);
+        assert_eq!(column_sums.shape(), "1x3");
+        assert_eq!(column_sums.data, vec![5.0, 7.0, 9.0]);
+
+        let row_sums = matrix.sum_axis(Axis::Cols);
+        assert_eq!(row_sums.shape(), "2x1");
+        assert_eq!(row_sums.data, vec![6.0, 15.0]);
+
+        assert_eq!(matrix.mean_axis(Axis::Rows).data, vec![2.5, 3.5, 4.5]);
+        assert_eq!(matrix.mean_axis(Axis::Cols).data, vec![2.0, 5.0]);
+    }
+
+    #[test]
+    fn test_matrix_var_and_std_axis() {
+        let matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 9.0]);
+
+        // Population variance of [1, 3, 5] and [2, 4, 9]
+        let variance = matrix.var_axis(Axis::Rows, 0);
+        assert_eq!(variance.shape(), "1x2");
+        assert!((variance.data[0] - 8.0 / 3.0).abs() < 1e-6);
+        assert!((variance.data[1] - 26.0 / 3.0).abs() < 1e-5);
+
+        // Sample variance
+        let variance = matrix.var_axis(Axis::Rows, 1);
+        assert_eq!(variance.data, vec![4.0, 13.0]);
+
+        let deviation = matrix.std_axis(Axis::Rows, 1);
+        assert_eq!(deviation.data, vec![2.0, 13.0_f32.sqrt()]);
+
+        // Variance of each row
+        let variance = matrix.var_axis(Axis::Cols, 0);
+        assert_eq!(variance.shape(), "3x1");
+        assert_eq!(variance.data, vec![0.25, 0.25, 4.0]);
+        assert_eq!(matrix.std_axis(Axis::Cols, 0).data, vec![0.5, 0.5, 2.0]);
+    }
+
+    #[test]
+    fn test_matrix_min_max_axis() {
+        let matrix = Matrix::from_vec(3, vec![3.0, -1.0, 2.0, 0.0, 5.0, -4.0]);
+
+        assert_eq!(matrix.min_axis(Axis::Rows).data, vec![0.0, -1.0, -4.0]);
+        assert_eq!(matrix.max_axis(Axis::Rows).data, vec![3.0, 5.0, 2.0]);
+        assert_eq!(matrix.min_axis(Axis::Cols).data, vec![-1.0, -4.0]);
+        assert_eq!(matrix.max_axis(Axis::Cols).data, vec![3.0, 5.0]);
+        assert_eq!(matrix.max_axis(Axis::Cols).shape(), "2x1");
+
+        // An empty axis gives the identity of the reduction
+        let empty = Matrix::new(0, 2);
+        assert_eq!(empty.min_axis(Axis::Rows).data, vec![f32::INFINITY; 2]);
+        assert_eq!(empty.max_axis(Axis::Rows).data, vec![f32::NEG_INFINITY; 2]);
+        assert_eq!(empty.max_axis(Axis::Cols).shape(), "0x1");
+    }
+
+    #[test]
+    fn test_matrix_argmax_argmin_axis() {
+        // Class scores for three samples and three classes
+        let scores = Matrix::from_vec(3, vec![0.1, 0.7, 0.2, 0.8, 0.1, 0.1, 0.3, 0.3, 0.4]);
+
+        let predicted = scores.argmax_axis(Axis::Cols).unwrap();
+        assert_eq!(predicted.data, vec![1.0, 0.0, 2.0]);
+        assert_eq!(predicted.shape(), "3x1");
+        assert_eq!(
+            scores.argmin_axis(Axis::Cols).unwrap().data,
+            vec![0.0, 1.0, 0.0]
+        );
+        let rows_max = scores.argmax_axis(Axis::Rows).unwrap();
+        assert_eq!(rows_max.data, vec![1.0, 0.0, 2.0]);
+        assert_eq!(rows_max.shape(), "1x3");
+        assert_eq!(
+            scores.argmin_axis(Axis::Rows).unwrap().data,
+            vec![0.0, 1.0, 1.0]
+        );
+
+        // First index wins when values are equal
+        let equal = Matrix::from_vec(3, vec![1.0, 1.0, 1.0]);
+        assert_eq!(equal.argmax_axis(Axis::Cols).unwrap().data, vec![0.0]);
+        assert_eq!(
+            equal.argmin_axis(Axis::Rows).unwrap().data,
+            vec![0.0, 0.0, 0.0]
+        );
+
+        // An empty axis has no index to return
+        let empty = Matrix::new(0, 3);
+        assert!(empty.argmax_axis(Axis::Rows).is_err());
+        assert!(empty.argmin_axis(Axis::Rows).is_err());
+        assert_eq!(empty.argmax_axis(Axis::Cols).unwrap().shape(), "0x1");
+    }
+
+    #[test]
+    fn test_matrix_f64_constructors() {
+        let matrix = Matrix::<f64>::new_typed(2, 3);
+        assert_eq!(matrix.data, vec![0.0_f64; 6]);
+
+        let identity = Matrix::<f64>::identity_typed(2);
+        assert_eq!(identity.data, vec![1.0, 0.0, 0.0, 1.0]);
+
+        let matrix = Matrix::from_vec_typed(2, vec![1.0_f64, 2.0, 3.0]);
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 0.0]);
+        assert_eq!(matrix.shape(), "2x2");
+
+        let random = Matrix::with_rand_range_typed(10, 10, -1.0_f64..=1.0);
+        assert!(random.data.iter().all(|x| (-1.0..=1.0).contains(x)));
+    }
+
+    #[test]
+    fn test_matrix_f64_operations() {
+        let a = Matrix::from_vec_typed(2, vec![1.0_f64, 2.0, 3.0, 4.0]);
+        let b = Matrix::<f64>::identity_typed(2);
+
+        assert_eq!(a.multiply(&b).unwrap().data, a.data);
+        assert_eq!((&a + &b).data, vec![2.0, 2.0, 3.0, 5.0]);
+        assert_eq!((2.0 * &a).data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(a.sum(), 10.0);
+        assert_eq!(a.get_transposed().data, vec![1.0, 3.0, 2.0, 4.0]);
+        assert!((a.determinant().unwrap() + 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_matrix_cast() {
+        let matrix: Matrix = Matrix::from_vec(2, vec![0.5, 1.0, -2.0, 4.0]);
+
+        let double: Matrix<f64> = matrix.cast();
+        assert_eq!(double.data, vec![0.5, 1.0, -2.0, 4.0]);
+        assert_eq!(double.shape(), "2x2");
+
+        // Casting back rounds to the nearest f32
+        let third = Matrix::from_vec_typed(1, vec![1.0_f64 / 3.0]);
+        assert_eq!(third.cast::<f32>().data, vec![1.0_f32 / 3.0]);
+    }
+
+    #[test]
+    fn test_matrix_with_rand_range_seeded() {
+        let first: Matrix = Matrix::with_rand_range_seeded(20, 10, -1.0..=1.0, 42);
+        let second: Matrix = Matrix::with_rand_range_seeded(20, 10, -1.0..=1.0, 42);
+        let other: Matrix = Matrix::with_rand_range_seeded(20, 10, -1.0..=1.0, 7);
+
+        assert_eq!(first.data, second.data);
+        assert_ne!(first.data, other.data);
+        assert!(first.data.iter().all(|x| (-1.0..=1.0).contains(x)));
+
+        // The same generator state gives the same matrix
+        let from_rng: Matrix =
+            Matrix::with_rand_range_rng(20, 10, -1.0..=1.0, &mut StdRng::seed_from_u64(42));
+        assert_eq!(first.data, from_rng.data);
+    }
+
+    #[test]
+    fn test_matrix_with_rand_normal() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let matrix = Matrix::with_rand_normal(200, 100, 3.0_f64, 2.0, &mut rng).unwrap();
+        assert_eq!(matrix.shape(), "200x100");
+
+        let mean = matrix.mean();
+        let variance =
+            matrix.data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / matrix.data.len() as f64;
+        assert!((mean - 3.0).abs() < 0.1, "mean was {mean}");
+        assert!(
+            (variance.sqrt() - 2.0).abs() < 0.1,
+            "std was {}",
+            variance.sqrt()
+        );
+
+        // Reproducible with the same seed
+        let first: Matrix =
+            Matrix::with_rand_normal(5, 5, 0.0, 1.0, &mut StdRng::seed_from_u64(9)).unwrap();
+        let second: Matrix =
+            Matrix::with_rand_normal(5, 5, 0.0, 1.0, &mut StdRng::seed_from_u64(9)).unwrap();
+        assert_eq!(first.data, second.data);
+
+        let invalid: Result<Matrix, MatrixError> =
+            Matrix::with_rand_normal(2, 2, 0.0, -1.0, &mut rng);
+        assert!(matches!(invalid, Err(MatrixError::InvalidDistribution(_))));
+    }
+
+    #[test]
+    fn test_matrix_with_rand_truncated_normal() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let matrix: Matrix =
+            Matrix::with_rand_truncated_normal(100, 100, 1.0, 0.5, &mut rng).unwrap();
+
+        // All values are within two standard deviations of the mean
+        assert!(matrix.data.iter().all(|x| (0.0..=2.0).contains(x)));
+        assert!((matrix.mean() - 1.0).abs() < 0.05);
+
+        let invalid: Result<Matrix, MatrixError> =
+            Matrix::with_rand_truncated_normal(2, 2, f32::NAN, 1.0, &mut rng);
+        assert!(matches!(invalid, Err(MatrixError::InvalidDistribution(_))));
+    }
+
+    #[test]
+    fn test_matrix_with_rand_bernoulli() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let matrix: Matrix = Matrix::with_rand_bernoulli(100, 100, 0.25, &mut rng).unwrap();
+
+        assert!(matrix.data.iter().all(|x| *x == 0.0 || *x == 1.0));
+        assert!((matrix.mean() - 0.25).abs() < 0.02);
+
+        let ones: Matrix = Matrix::with_rand_bernoulli(3, 3, 1.0, &mut rng).unwrap();
+        assert_eq!(ones.data, vec![1.0; 9]);
+
+        let invalid: Result<Matrix, MatrixError> = Matrix::with_rand_bernoulli(2, 2, 1.5, &mut rng);
+        assert!(matches!(invalid, Err(MatrixError::InvalidDistribution(_))));
+    }
+
+    #[test]
+    fn test_matrix_weight_initializations() {
+        let mut rng = StdRng::seed_from_u64(4);
+
+        // fan_in = 200 and fan_out = 100
+        let xavier_limit = (6.0_f32 / 300.0).sqrt();
+        let xavier: Matrix = Matrix::with_xavier_uniform(200, 100, &mut rng);
+        assert!(xavier.data.iter().all(|x| x.abs() <= xavier_limit));
+
+        let he_limit = (6.0_f32 / 200.0).sqrt();
+        let he: Matrix = Matrix::with_he_uniform(200, 100, &mut rng);
+        assert!(he.data.iter().all(|x| x.abs() <= he_limit));
+        assert!(he.data.iter().any(|x| x.abs() > xavier_limit));
+
+        let std_of = |matrix: &Matrix<f64>| {
+            let mean = matrix.mean();
+            (matrix.data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / matrix.data.len() as f64)
+                .sqrt()
+        };
+
+        let xavier_normal = Matrix::<f64>::with_xavier_normal(200, 100, &mut rng);
+        assert!((std_of(&xavier_normal) - (2.0_f64 / 300.0).sqrt()).abs() < 0.005);
+
+        let he_normal = Matrix::<f64>::with_he_normal(200, 100, &mut rng);
+        assert!((std_of(&he_normal) - (2.0_f64 / 200.0).sqrt()).abs() < 0.005);
+    }
+
+    #[test]
+    fn test_matrix_hstack_vstack() {
+        let a: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b: Matrix = Matrix::from_vec(1, vec![5.0, 6.0]);
+
+        let horizontal = a.hstack(&b).unwrap();
+        assert_eq!(horizontal.shape(), "2x3");
+        assert_eq!(horizontal.data, vec![1.0, 2.0, 5.0, 3.0, 4.0, 6.0]);
+
+        let c: Matrix = Matrix::from_vec(2, vec![7.0, 8.0]);
+        let vertical = a.vstack(&c).unwrap();
+        assert_eq!(vertical.shape(), "3x2");
+        assert_eq!(vertical.data, vec![1.0, 2.0, 3.0, 4.0, 7.0, 8.0]);
+
+        assert_eq!(
+            a.hstack(&c).err(),
+            Some(MatrixError::ShapeMismatch {
+                first_matrix_shape: "2x2".to_string(),
+                second_matrix_shape: "1x2".to_string(),
+            })
+        );
+        assert!(a.vstack(&b).is_err());
+    }
+
+    #[test]
+    fn test_matrix_concat() {
+        let a: Matrix = Matrix::from_vec(1, vec![1.0, 2.0]);
+        let b: Matrix = Matrix::from_vec(2, vec![3.0, 4.0, 5.0, 6.0]);
+        let c: Matrix = Matrix::from_vec(1, vec![7.0, 8.0]);
+
+        let joined = Matrix::concat(&[&a, &b, &c], Axis::Cols).unwrap();
+        assert_eq!(joined.shape(), "2x4");
+        assert_eq!(joined.data, vec![1.0, 3.0, 4.0, 7.0, 2.0, 5.0, 6.0, 8.0]);
+
+        let stacked = Matrix::concat(&[&b, &b], Axis::Rows).unwrap();
+        assert_eq!(stacked.shape(), "4x2");
+        assert_eq!(stacked.data, [b.data.clone(), b.data.clone()].concat());
+
+        assert!(Matrix::concat(&[&a, &b], Axis::Rows).is_err());
+        assert_eq!(
+            Matrix::<f32>::concat(&[], Axis::Rows).unwrap().shape(),
+            "0x0"
+        );
+    }
+
+    #[test]
+    fn test_matrix_split() {
+        let matrix: Matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let (top, bottom) = matrix.split_rows(1).unwrap();
+        assert_eq!(top.data, vec![1.0, 2.0, 3.0]);
+        assert_eq!(bottom.data, vec![4.0, 5.0, 6.0]);
+        assert_eq!((top.rows, bottom.rows), (1, 1));
+
+        // Splitting features from labels
+        let (features, labels) = matrix.split_cols(2).unwrap();
+        assert_eq!(features.shape(), "2x2");
+        assert_eq!(features.data, vec![1.0, 2.0, 4.0, 5.0]);
+        assert_eq!(labels.shape(), "2x1");
+        assert_eq!(labels.data, vec![3.0, 6.0]);
+
+        // Splitting and joining again gives the original matrix
+        assert_eq!(features.hstack(&labels).unwrap().data, matrix.data);
+
+        let (empty, all) = matrix.split_rows(0).unwrap();
+        assert_eq!(empty.shape(), "0x3");
+        assert_eq!(all.data, matrix.data);
+
+        assert!(matches!(
+            matrix.split_rows(3),
+            Err(MatrixError::IllegalRange(_))
+        ));
+        assert!(matches!(
+            matrix.split_cols(4),
+            Err(MatrixError::IllegalRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_matrix_insert_and_remove_row() {
+        let mut matrix: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+
+        matrix.insert_row(1, &[9.0, 9.0]).unwrap();
+        assert_eq!(matrix.shape(), "3x2");
+        assert_eq!(matrix.data, vec![1.0, 2.0, 9.0, 9.0, 3.0, 4.0]);
+
+        matrix.insert_row(3, &[5.0, 6.0]).unwrap();
+        assert_eq!(matrix.get_row(3), Some(vec![5.0, 6.0]));
+
+        assert_eq!(matrix.remove_row(1).unwrap(), vec![9.0, 9.0]);
+        assert_eq!(matrix.shape(), "3x2");
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert!(matches!(
+            matrix.insert_row(0, &[1.0]),
+            Err(MatrixError::ShapeMismatch { .. })
+        ));
+        assert!(matches!(
+            matrix.insert_row(5, &[1.0, 2.0]),
+            Err(MatrixError::IllegalRange(_))
+        ));
+        assert!(matches!(
+            matrix.remove_row(3),
+            Err(MatrixError::IllegalRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_matrix_insert_and_remove_col() {
+        let mut design: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        // Adding a bias column of ones
+        design.insert_col(0, &vec![1.0; design.rows]).unwrap();
+        assert_eq!(design.shape(), "3x3");
+        assert_eq!(
+            design.data,
+            vec![1.0, 1.0, 2.0, 1.0, 3.0, 4.0, 1.0, 5.0, 6.0]
+        );
+
+        design.insert_col(3, &[7.0, 8.0, 9.0]).unwrap();
+        assert_eq!(design.get_col(3), Some(vec![7.0, 8.0, 9.0]));
+
+        assert_eq!(design.remove_col(0).unwrap(), vec![1.0, 1.0, 1.0]);
+        assert_eq!(design.shape(), "3x3");
+        assert_eq!(
+            design.data,
+            vec![1.0, 2.0, 7.0, 3.0, 4.0, 8.0, 5.0, 6.0, 9.0]
+        );
+
+        assert_eq!(
+            design.insert_col(1, &[1.0, 2.0]).err(),
+            Some(MatrixError::ShapeMismatch {
+                first_matrix_shape: "3x3".to_string(),
+                second_matrix_shape: "2x1".to_string(),
+            })
+        );
+        assert!(matches!(
+            design.insert_col(4, &[1.0, 2.0, 3.0]),
+            Err(MatrixError::IllegalRange(_))
+        ));
+        assert!(matches!(
+            design.remove_col(3),
+            Err(MatrixError::IllegalRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_matrix_map() {
+        let matrix: Matrix = Matrix::from_vec(2, vec![-1.0, 2.0, -3.0, 4.0]);
+
+        let relu = matrix.map(|x| x.max(0.0));
+        assert_eq!(relu.data, vec![0.0, 2.0, 0.0, 4.0]);
+        assert_eq!(relu.shape(), "2x2");
+
+        let double: Matrix<f64> = matrix.map(|x| x as f64 * 2.0);
+        assert_eq!(double.data, vec![-2.0, 4.0, -6.0, 8.0]);
+
+        let mut sigmoid = matrix.clone();
+        sigmoid.map_inplace(|x| 1.0 / (1.0 + (-x).exp()));
+        assert!(sigmoid.data.iter().all(|x| (0.0..=1.0).contains(x)));
+        assert!((sigmoid.data[1] - 0.880797).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_matrix_zip_map() {
+        let a: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b: Matrix = Matrix::from_vec(2, vec![4.0, 3.0, 2.0, 1.0]);
+
+        let max = a.zip_map(&b, f32::max).unwrap();
+        assert_eq!(max.data, vec![4.0, 3.0, 3.0, 4.0]);
+
+        let c: Matrix = Matrix::new(1, 4);
+        assert!(matches!(
+            a.zip_map(&c, |x, y| x + y),
+            Err(MatrixError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_matrix_apply_rows() {
+        let mut matrix: Matrix = Matrix::from_vec(3, vec![1.0, 1.0, 2.0, 0.0, 3.0, 1.0]);
+
+        // Normalize each row so it sums to 1
+        matrix.apply_rows(|row| {
+            let sum: f32 = row.iter().sum();
+            row.iter_mut().for_each(|x| *x /= sum);
+        });
+
+        assert_eq!(matrix.data, vec![0.25, 0.25, 0.5, 0.0, 0.75, 0.25]);
+    }
+
+    #[test]
+    fn test_matrix_iter_rows_and_cols() {
+        let mut matrix: Matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let rows: Vec<&[f32]> = matrix.iter_rows().collect();
+        assert_eq!(rows, vec![&[1.0, 2.0, 3.0][..], &[4.0, 5.0, 6.0][..]]);
+
+        let cols: Vec<Vec<f32>> = matrix
+            .iter_cols()
+            .map(|col| col.copied().collect())
+            .collect();
+        assert_eq!(cols, vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]);
+
+        for row in matrix.iter_rows_mut() {
+            row[0] = 0.0;
+        }
+        for value in matrix.iter_col_mut(2).unwrap() {
+            *value *= 10.0;
+        }
+        assert_eq!(matrix.data, vec![0.0, 2.0, 30.0, 0.0, 5.0, 60.0]);
+        assert!(matrix.iter_col_mut(3).is_none());
+    }
+
+    #[test]
+    fn test_matrix_iter_rows_without_cols() {
+        let mut matrix = Matrix::new(3, 0);
+
+        assert_eq!(matrix.iter_rows().count(), 3);
+        assert_eq!(matrix.iter_rows_mut().count(), 3);
+        assert!(matrix.iter_rows_mut().all(|row| row.is_empty()));
+        assert_eq!(matrix.iter_cols().count(), 0);
+    }
+
+    #[test]
+    fn test_matrix_iter_indexed() {
+        let matrix: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let indexed: Vec<((usize, usize), f32)> = matrix
+            .iter_indexed()
+            .map(|(index, x)| (index, *x))
+            .collect();
+        assert_eq!(indexed[0], ((0, 0), 1.0));
+        assert_eq!(indexed[3], ((1, 1), 4.0));
+        assert_eq!(indexed[4], ((2, 0), 5.0));
+        assert_eq!(indexed.len(), 6);
+
+        for (index, value) in matrix.iter_indexed() {
+            assert_eq!(matrix[index], *value);
+        }
+    }
+
+    #[test]
+    fn test_matrix_display() {
+        let matrix: Matrix = Matrix::from_vec(3, vec![1.0, -2.5, 3.0, 40.0, 5.0, 6.25]);
+        assert_eq!(format!("{matrix}"), "[[   1 -2.5    3]\n [  40    5 6.25]]");
+        assert_eq!(
+            format!("{matrix:.2}"),
+            "[[ 1.00 -2.50  3.00]\n [40.00  5.00  6.25]]"
+        );
+
+        let row: Matrix<f64> = Matrix::from_vec_typed(2, vec![0.5, 1.0]);
+        assert_eq!(format!("{row:.1}"), "[[0.5 1.0]]");
+
+        let empty: Matrix = Matrix::new(0, 0);
+        assert_eq!(format!("{empty}"), "[]");
+    }
+
+    #[test]
+    fn test_matrix_display_truncated() {
+        // 1600 values are above the threshold, so only the edges are printed
+        let matrix: Matrix = Matrix::from_vec(40, (0..1600).map(|x| x as f32).collect());
+        let printed = format!("{matrix}");
+        let lines: Vec<&str> = printed.lines().collect();
+
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "[[   0    1    2 ...   37   38   39]");
+        assert_eq!(lines[3], " ...");
+        assert_eq!(lines[6], " [1560 1561 1562 ... 1597 1598 1599]]");
+
+        // Small matrices are printed in full
+        let small: Matrix = Matrix::new(10, 10);
+        assert_eq!(format!("{small}").lines().count(), 10);
+        assert!(!format!("{small}").contains("..."));
+    }
+
+    #[test]
+    fn test_matrix_debug_and_eq() {
+        let a: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let reshaped: Matrix = Matrix::from_vec(4, vec![1.0, 2.0, 3.0, 4.0]);
+
+        assert_eq!(a, b);
+        assert_ne!(a, reshaped);
+        assert_eq!(a.get_transposed().get_transposed(), a);
+        assert_eq!(format!("{a:?}"), "Matrix(2x2)\n[[1 2]\n [3 4]]");
+        assert_eq!(format!("{:?}", Matrix::new(0, 3)), "Matrix(0x3)\n[]");
+
+        // Large matrices are truncated in the same way as `Display`
+        let large = Matrix::new(50, 50);
+        let printed = format!("{large:?}");
+        assert_eq!(printed.lines().next(), Some("Matrix(50x50)"));
+        assert_eq!(printed.lines().count(), 8);
+        assert!(printed.contains("0 0 0 ... 0 0 0"));
+    }
+
+    #[test]
+    fn test_matrix_approx_eq() {
+        let a: Matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b: Matrix = Matrix::from_vec(2, vec![1.0001, 2.0, 2.9999, 4.0]);
+
+        assert!(a.approx_eq(&b, 1e-3));
+        assert!(!a.approx_eq(&b, 1e-5));
+        assert!(!a.approx_eq(&Matrix::from_vec(4, a.data.clone()), 1e-3));
+
+        let infinite: Matrix = Matrix::from_vec(1, vec![f32::INFINITY]);
+        assert!(infinite.approx_eq(&infinite.clone(), 1e-3));
+
+        let nan: Matrix = Matrix::from_vec(1, vec![f32::NAN]);
+        assert!(!nan.approx_eq(&nan.clone(), 1e-3));
+    }
 }
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use rustic_ml::data_utils::matrix::Matrix;
+    use rustic_ml::data_utils::matrix_io::MatrixIoError;
+
+    /// Build the bytes of a version 1.0 `.npy` file with the given header and values
+    fn npy_bytes(header: &str, values: &[u8]) -> Vec<u8> {
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(values);
+        bytes
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rustic_ml_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let matrix: Matrix = Matrix::from_vec(3, vec![1.5, -2.0, 3.25, 0.1, f32::MAX, f32::MIN]);
+
+        let mut bytes: Vec<u8> = Vec::new();
+        matrix.write_binary(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 24 + 6 * 4);
+
+        let read: Matrix = Matrix::read_binary(bytes.as_slice()).unwrap();
+        assert_eq!(read, matrix);
+
+        // Reading as f64 keeps the exact values
+        let double = Matrix::<f64>::read_binary(bytes.as_slice()).unwrap();
+        assert_eq!(double, matrix.cast::<f64>());
+    }
+
+    #[test]
+    fn test_binary_save_and_load() {
+        let path = temp_path("matrix.bin");
+        let matrix = Matrix::from_vec_typed(2, vec![1.0_f64 / 3.0, 2.0, 3.0, 4.0]);
+
+        matrix.save_binary(&path).unwrap();
+        let loaded = Matrix::<f64>::load_binary(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, matrix);
+    }
+
+    #[test]
+    fn test_binary_invalid() {
+        let matrix: Matrix = Matrix::identity(2);
+        let mut bytes: Vec<u8> = Vec::new();
+        matrix.write_binary(&mut bytes).unwrap();
+
+        // Missing values
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(matches!(
+            Matrix::<f32>::read_binary(truncated),
+            Err(MatrixIoError::InvalidFormat(_))
+        ));
+
+        // Not a binary matrix
+        assert!(matches!(
+            Matrix::<f32>::read_binary(&[0u8; 32][..]),
+            Err(MatrixIoError::InvalidFormat(_))
+        ));
+
+        // Too short for a header
+        assert!(matches!(
+            Matrix::<f32>::read_binary(&bytes[..10]),
+            Err(MatrixIoError::Io(_))
+        ));
+
+        assert!(matches!(
+            Matrix::<f32>::load_binary(temp_path("does_not_exist.bin")),
+            Err(MatrixIoError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_write_npy_header() {
+        let matrix = Matrix::from_vec_typed(2, vec![1.0_f64, 2.0, 3.0, 4.0]);
+        let mut bytes: Vec<u8> = Vec::new();
+        matrix.write_npy(&mut bytes).unwrap();
+
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+
+        let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+        assert!(header.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }"));
+        assert!(header.ends_with(" \n"));
+
+        assert_eq!(bytes.len(), 10 + header_len + 4 * 8);
+        assert_eq!(
+            &bytes[10 + header_len..10 + header_len + 8],
+            &1.0_f64.to_le_bytes()
+        );
+
+        let single: Matrix = Matrix::new(1, 1);
+        let mut bytes: Vec<u8> = Vec::new();
+        single.write_npy(&mut bytes).unwrap();
+        assert!(std::str::from_utf8(&bytes[10..])
+            .unwrap()
+            .contains("'descr': '<f4'"));
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let path = temp_path("matrix.npy");
+        let matrix: Matrix = Matrix::from_vec(3, vec![0.5, -1.0, 2.0, 4.0, 8.0, -16.0]);
+
+        matrix.save_npy(&path).unwrap();
+        let loaded: Matrix = Matrix::load_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, matrix);
+    }
+
+    #[test]
+    fn test_read_npy_layouts() {
+        let values: Vec<u8> = [1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+
+        // Fortran order is column-major, so the values are the columns of a (2x3) matrix
+        let fortran = npy_bytes(
+            "{'descr': '<f4', 'fortran_order': True, 'shape': (2, 3), }\n",
+            &values,
+        );
+        let matrix = Matrix::<f64>::read_npy(fortran.as_slice()).unwrap();
+        assert_eq!(matrix.shape(), "2x3");
+        assert_eq!(matrix.data, vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+
+        // A 1-dimensional array is a row vector
+        let vector = npy_bytes(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (6,), }\n",
+            &values,
+        );
+        let matrix: Matrix = Matrix::read_npy(vector.as_slice()).unwrap();
+        assert_eq!(matrix.shape(), "1x6");
+
+        // Version 2.0 stores the header length as a u32
+        let header = "{\"descr\": \"<f4\", \"fortran_order\": False, \"shape\": (3, 2)}\n";
+        let mut version_2 = b"\x93NUMPY\x02\x00".to_vec();
+        version_2.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        version_2.extend_from_slice(header.as_bytes());
+        version_2.extend_from_slice(&values);
+        let matrix: Matrix = Matrix::read_npy(version_2.as_slice()).unwrap();
+        assert_eq!(matrix.shape(), "3x2");
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_read_npy_invalid() {
+        let integers = npy_bytes(
+            "{'descr': '<i8', 'fortran_order': False, 'shape': (1,), }\n",
+            &[0; 8],
+        );
+        match Matrix::<f32>::read_npy(integers.as_slice()) {
+            Err(MatrixIoError::UnsupportedDtype(dtype)) => assert_eq!(dtype, "<i8"),
+            _ => panic!("Expected an unsupported dtype"),
+        }
+
+        let three_dimensional = npy_bytes(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (1, 1, 1), }\n",
+            &[0; 4],
+        );
+        assert!(matches!(
+            Matrix::<f32>::read_npy(three_dimensional.as_slice()),
+            Err(MatrixIoError::InvalidFormat(_))
+        ));
+
+        let missing_values = npy_bytes(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 2), }\n",
+            &[0; 12],
+        );
+        assert!(matches!(
+            Matrix::<f32>::read_npy(missing_values.as_slice()),
+            Err(MatrixIoError::InvalidFormat(_))
+        ));
+
+        let missing_shape = npy_bytes("{'descr': '<f4', 'fortran_order': False}\n", &[0; 4]);
+        assert!(matches!(
+            Matrix::<f32>::read_npy(missing_shape.as_slice()),
+            Err(MatrixIoError::InvalidFormat(_))
+        ));
+
+        assert!(matches!(
+            Matrix::<f32>::read_npy(&b"NOT A NUMPY FILE"[..]),
+            Err(MatrixIoError::InvalidFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,265 @@
+#[cfg(test)]
+mod tests {
+    use rustic_ml::data_utils::{
+        matrix::{Axis, Matrix, MatrixError},
+        matrix_view::{MatrixView, MatrixViewMut},
+    };
+
+    /// 3x4 matrix with the values 1 to 12
+    fn matrix_3x4() -> Matrix {
+        Matrix::from_vec(
+            4,
+            vec![
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_view_of_region() {
+        let matrix = matrix_3x4();
+        let view = matrix.view(1..3, 1..4).unwrap();
+
+        assert_eq!(view.shape(), "2x3");
+        assert_eq!(view.rows(), 2);
+        assert_eq!(view.cols(), 3);
+        assert_eq!(view[(0, 0)], 6.0);
+        assert_eq!(view[(1, 2)], 12.0);
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.get_row(1), Some(vec![10.0, 11.0, 12.0]));
+        assert_eq!(view.get_row_as_slice(0), Some(&[6.0, 7.0, 8.0][..]));
+        assert_eq!(view.get_col(0), Some(vec![6.0, 10.0]));
+        assert_eq!(view.to_matrix().data, vec![6.0, 7.0, 8.0, 10.0, 11.0, 12.0]);
+    }
+
+    #[test]
+    fn test_view_err() {
+        let matrix = matrix_3x4();
+
+        match matrix.view(0..4, 0..2) {
+            Err(MatrixError::IllegalRange(_)) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+
+        match matrix.view(1..1, 0..2) {
+            Err(MatrixError::IllegalRange(_)) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+
+        // Sub views are checked against the shape of the view
+        let view = matrix.view(0..2, 0..2).unwrap();
+        match view.view(0..2, 0..3) {
+            Err(MatrixError::IllegalRange(_)) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+    }
+
+    #[test]
+    fn test_view_with_strides() {
+        let data: Vec<f32> = (0..12).map(|x| x as f32).collect();
+
+        // Every second row and every second column of a 3x4 layout
+        let view = MatrixView::new(&data, 2, 2, 8, 2).unwrap();
+        assert_eq!(view.to_matrix().data, vec![0.0, 2.0, 8.0, 10.0]);
+        assert!(!view.has_contiguous_rows());
+        assert_eq!(view.get_row_as_slice(0), None);
+
+        // Broadcast a single row by using a row stride of 0
+        let view = MatrixView::new(&data[..3], 2, 3, 0, 1).unwrap();
+        assert_eq!(view.to_matrix().data, vec![0.0, 1.0, 2.0, 0.0, 1.0, 2.0]);
+
+        // Views that would read outside of the data are rejected
+        match MatrixView::new(&data, 3, 4, 5, 1) {
+            Err(MatrixError::IllegalRange(_)) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+    }
+
+    #[test]
+    fn test_view_transpose() {
+        let matrix = matrix_3x4();
+        let view = matrix.as_view().transpose();
+
+        assert_eq!(view.shape(), "4x3");
+        assert_eq!(view[(3, 0)], 4.0);
+        assert_eq!(view.to_matrix().data, matrix.get_transposed().data);
+
+        // Sub view of a transposed view
+        let sub = view.view(1..3, 0..2).unwrap();
+        assert_eq!(sub.to_matrix().data, vec![2.0, 6.0, 3.0, 7.0]);
+    }
+
+    #[test]
+    fn test_view_multiply() {
+        let matrix = matrix_3x4();
+        let a = matrix.view(0..2, 0..2).unwrap();
+        let b = matrix.view(1..3, 2..4).unwrap();
+
+        let expected = a.to_matrix().multiply(&b.to_matrix()).unwrap();
+        assert_eq!(a.multiply(&b).unwrap().data, expected.data);
+
+        // Multiplying with a transposed view gives A * A^T
+        let full = matrix.as_view();
+        let gram = full.multiply(&full.transpose()).unwrap();
+        assert_eq!(gram.shape(), "3x3");
+        assert_eq!(gram.data[0], 30.0);
+
+        match full.multiply(&full) {
+            Err(MatrixError::MatrixMultiply) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+    }
+
+    #[test]
+    fn test_view_elementwise() {
+        let matrix = matrix_3x4();
+        let a = matrix.view(0..2, 0..2).unwrap();
+        let b = matrix.view(1..3, 2..4).unwrap();
+
+        assert_eq!(
+            a.add_elementwise(&b).unwrap().data,
+            vec![8.0, 10.0, 16.0, 18.0]
+        );
+        assert_eq!(
+            b.sub_elementwise(&a).unwrap().data,
+            vec![6.0, 6.0, 6.0, 6.0]
+        );
+        assert_eq!(
+            a.mul_elementwise(&b).unwrap().data,
+            vec![7.0, 16.0, 55.0, 72.0]
+        );
+        assert_eq!(
+            b.div_elementwise(&a).unwrap().data,
+            vec![7.0, 4.0, 11.0 / 5.0, 2.0]
+        );
+
+        let c = matrix.view(0..2, 0..3).unwrap();
+        match a.add_elementwise(&c) {
+            Err(MatrixError::ShapeMismatch { .. }) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+
+        let zeros = Matrix::new(2, 2);
+        match a.div_elementwise(&zeros.as_view()) {
+            Err(MatrixError::DivideByZero) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+    }
+
+    #[test]
+    fn test_view_reductions() {
+        let matrix = matrix_3x4();
+        let view = matrix.view(1..3, 1..3).unwrap();
+
+        assert_eq!(view.sum(), 34.0);
+        assert_eq!(view.mean(), 8.5);
+        assert_eq!(view.sum_axis(Axis::Rows).data, vec![16.0, 18.0]);
+        assert_eq!(view.sum_axis(Axis::Cols).data, vec![13.0, 21.0]);
+        assert_eq!(view.mean_axis(Axis::Cols).data, vec![6.5, 10.5]);
+        assert_eq!(view.mean_axis(Axis::Cols).shape(), "2x1");
+    }
+
+    #[test]
+    fn test_view_mut_writes_to_matrix() {
+        let mut matrix = matrix_3x4();
+
+        {
+            let mut view = matrix.view_mut(1..3, 2..4).unwrap();
+            view[(0, 0)] = 0.0;
+            *view.get_mut(1, 1).unwrap() = -1.0;
+            assert_eq!(view.get_mut(2, 0), None);
+        }
+        assert_eq!(matrix.data[6], 0.0);
+        assert_eq!(matrix.data[11], -1.0);
+
+        // Only the region of the view is changed
+        matrix.view_mut(0..2, 0..2).unwrap().fill(9.0);
+        assert_eq!(
+            matrix.data,
+            vec![9.0, 9.0, 3.0, 4.0, 9.0, 9.0, 0.0, 8.0, 9.0, 10.0, 11.0, -1.0]
+        );
+    }
+
+    #[test]
+    fn test_view_mut_scalar_operations() {
+        let mut matrix = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+
+        let mut view = matrix.view_mut(0..2, 1..2).unwrap();
+        view.add_f(1.0);
+        view.scale_f(2.0);
+        view.sub_f(2.0);
+        assert_eq!(view.div_f(0.0), Err(MatrixError::DivideByZero));
+        view.div_f(2.0).unwrap();
+        assert_eq!(view.to_matrix().data, vec![2.0, 4.0]);
+
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_view_mut_copy_and_assign() {
+        let source = Matrix::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let mut matrix = Matrix::new(3, 3);
+
+        {
+            let mut view = matrix.view_mut(1..3, 1..3).unwrap();
+            view.copy_from(&source.as_view()).unwrap();
+            view += &source.as_view();
+            view -= &Matrix::identity(2).as_view();
+        }
+        assert_eq!(
+            matrix.data,
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 4.0, 0.0, 6.0, 7.0]
+        );
+    }
+
+    #[test]
+    fn test_view_mut_with_strides() {
+        let mut data = vec![0.0; 6];
+
+        // The first column of a 3x2 layout
+        let mut view = MatrixViewMut::new(&mut data, 3, 1, 2, 1).unwrap();
+        view.fill(1.0);
+        assert_eq!(view.as_view().sum(), 3.0);
+
+        let mut sub = view.view_mut(1..3, 0..1).unwrap();
+        sub.add_f(1.0);
+        assert_eq!(sub.to_matrix().data, vec![2.0, 2.0]);
+
+        assert_eq!(data, vec![1.0, 0.0, 2.0, 0.0, 2.0, 0.0]);
+
+        match MatrixViewMut::new(&mut data, 2, 2, 4, 2) {
+            Err(MatrixError::IllegalRange(_)) => (), // Success
+            _ => panic!("Expected to create an error"),
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "index out of bounds: the view has shape 2x2 but the index is (2, 0)"
+    )]
+    fn test_view_index_out_of_bounds() {
+        let matrix = matrix_3x4();
+        let view = matrix.view(0..2, 0..2).unwrap();
+        let _ = view[(2, 0)];
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "index out of bounds: the view has shape 2x3 but the index is (0, 3)"
+    )]
+    fn test_view_mut_index_out_of_bounds() {
+        let mut matrix = matrix_3x4();
+        let mut view = matrix.view_mut(1..3, 0..3).unwrap();
+        view[(1, 2)] = 5.0;
+        view[(0, 3)] = 5.0;
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match the shape")]
+    fn test_view_mut_add_assign_shape_mismatch() {
+        let source = matrix_3x4();
+        let mut matrix = Matrix::new(2, 2);
+        let mut view = matrix.as_view_mut();
+        view += &source.as_view();
+    }
+}
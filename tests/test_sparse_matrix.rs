@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use rustic_ml::data_utils::matrix::{Matrix, MatrixError};
+    use rustic_ml::data_utils::sparse_matrix::{SparseFormat, SparseMatrix};
+
+    /// The dense matrix used in most tests, with 4 of 12 values stored
+    fn dense() -> Matrix {
+        Matrix::from_vec(
+            4,
+            vec![
+                1.0, 0.0, 0.0, 2.0, //
+                0.0, 0.0, 3.0, 0.0, //
+                0.0, 4.0, 0.0, 0.0,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_sparse_from_dense() {
+        for format in [SparseFormat::Csr, SparseFormat::Csc] {
+            let sparse = SparseMatrix::from_dense(&dense(), format);
+
+            assert_eq!(sparse.format(), format);
+            assert_eq!(sparse.shape(), "3x4");
+            assert_eq!(sparse.nnz(), 4);
+            assert!((sparse.density() - 4.0 / 12.0).abs() < 1e-12);
+            assert_eq!(sparse.to_dense(), dense());
+
+            assert_eq!(sparse.get(0, 3), Some(2.0));
+            assert_eq!(sparse.get(2, 1), Some(4.0));
+            assert_eq!(sparse.get(1, 1), Some(0.0));
+            assert_eq!(sparse.get(3, 0), None);
+        }
+
+        let sparse: SparseMatrix = SparseMatrix::from(&dense());
+        assert_eq!(sparse.format(), SparseFormat::Csr);
+        assert_eq!(Matrix::from(&sparse), dense());
+    }
+
+    #[test]
+    fn test_sparse_from_triplets() {
+        let triplets = [
+            (2, 1, 4.0),
+            (0, 3, 2.0),
+            (1, 2, 1.0),
+            (0, 0, 1.0),
+            (1, 2, 2.0),
+        ];
+
+        for format in [SparseFormat::Csr, SparseFormat::Csc] {
+            let sparse: SparseMatrix =
+                SparseMatrix::from_triplets(3, 4, &triplets, format).unwrap();
+
+            // The two values at (1, 2) are summed
+            assert_eq!(sparse.nnz(), 4);
+            assert_eq!(sparse.to_dense(), dense());
+        }
+
+        let outside: Result<SparseMatrix, MatrixError> =
+            SparseMatrix::from_triplets(3, 4, &[(3, 0, 1.0)], SparseFormat::Csr);
+        assert!(matches!(outside, Err(MatrixError::IllegalRange(_))));
+
+        let empty: SparseMatrix = SparseMatrix::new(2, 5, SparseFormat::Csc);
+        assert_eq!(empty.nnz(), 0);
+        assert_eq!(empty.to_dense(), Matrix::new(2, 5));
+    }
+
+    #[test]
+    fn test_sparse_format_conversion() {
+        let csr = SparseMatrix::from_dense(&dense(), SparseFormat::Csr);
+        let csc = csr.to_csc();
+
+        assert_eq!(csc.format(), SparseFormat::Csc);
+        assert_eq!(csc, SparseMatrix::from_dense(&dense(), SparseFormat::Csc));
+        assert_eq!(csc.to_csr(), csr);
+
+        // CSR gives the values by row, and CSC by column
+        let by_row: Vec<(usize, usize, f32)> = csr.iter().collect();
+        assert_eq!(
+            by_row,
+            vec![(0, 0, 1.0), (0, 3, 2.0), (1, 2, 3.0), (2, 1, 4.0)]
+        );
+        let by_col: Vec<(usize, usize, f32)> = csc.iter().collect();
+        assert_eq!(
+            by_col,
+            vec![(0, 0, 1.0), (2, 1, 4.0), (1, 2, 3.0), (0, 3, 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_sparse_transpose() {
+        let mut sparse = SparseMatrix::from_dense(&dense(), SparseFormat::Csr);
+
+        let transposed = sparse.get_transposed();
+        assert_eq!(transposed.shape(), "4x3");
+        assert_eq!(transposed.format(), SparseFormat::Csc);
+        assert_eq!(transposed.to_dense(), dense().get_transposed());
+
+        sparse.transpose();
+        assert_eq!(sparse, transposed);
+        assert_eq!(sparse.to_csr().to_dense(), dense().get_transposed());
+    }
+
+    #[test]
+    fn test_sparse_scaling() {
+        let mut sparse = SparseMatrix::from_dense(&dense(), SparseFormat::Csr);
+
+        sparse.scale_f(2.0);
+        assert_eq!(sparse.to_dense(), &dense() * 2.0);
+
+        sparse.div_f(4.0).unwrap();
+        assert_eq!(sparse.to_dense(), &dense() * 0.5);
+
+        assert_eq!(sparse.div_f(0.0), Err(MatrixError::DivideByZero));
+        assert_eq!(sparse.to_dense(), &dense() * 0.5);
+    }
+
+    #[test]
+    fn test_sparse_multiply_dense() {
+        let rhs: Matrix = Matrix::from_vec(2, (0..8).map(|x| x as f32).collect());
+        let expected = dense().multiply(&rhs).unwrap();
+
+        for format in [SparseFormat::Csr, SparseFormat::Csc] {
+            let sparse = SparseMatrix::from_dense(&dense(), format);
+            assert_eq!(sparse.multiply_dense(&rhs).unwrap(), expected);
+        }
+
+        let sparse = SparseMatrix::from_dense(&dense(), SparseFormat::Csr);
+        assert_eq!(
+            sparse.multiply_dense(&Matrix::new(3, 2)),
+            Err(MatrixError::MatrixMultiply)
+        );
+    }
+
+    #[test]
+    fn test_sparse_dense_multiply() {
+        let lhs: Matrix = Matrix::from_vec(3, (0..6).map(|x| x as f32).collect());
+        let expected = lhs.multiply(&dense()).unwrap();
+
+        for format in [SparseFormat::Csr, SparseFormat::Csc] {
+            let sparse = SparseMatrix::from_dense(&dense(), format);
+            assert_eq!(sparse.dense_multiply(&lhs).unwrap(), expected);
+        }
+
+        // Multiplying over an empty inner dimension gives zeros
+        for format in [SparseFormat::Csr, SparseFormat::Csc] {
+            let empty: SparseMatrix = SparseMatrix::new(0, 3, format);
+            assert_eq!(
+                empty.dense_multiply(&Matrix::new(2, 0)).unwrap(),
+                Matrix::new(2, 3)
+            );
+        }
+
+        let sparse = SparseMatrix::from_dense(&dense(), SparseFormat::Csc);
+        assert_eq!(
+            sparse.dense_multiply(&Matrix::new(2, 4)),
+            Err(MatrixError::MatrixMultiply)
+        );
+    }
+
+    #[test]
+    fn test_sparse_one_hot_f64() {
+        // One-hot encoding of the categories [2, 0, 1, 2]
+        let categories = [2, 0, 1, 2];
+        let triplets: Vec<(usize, usize, f64)> = categories
+            .iter()
+            .enumerate()
+            .map(|(row, category)| (row, *category, 1.0))
+            .collect();
+        let one_hot = SparseMatrix::from_triplets(4, 3, &triplets, SparseFormat::Csr).unwrap();
+
+        // Multiplying with the embedding of each category selects the rows of the embedding
+        let embedding = Matrix::from_vec_typed(2, vec![0.5_f64, 1.5, 2.5, 3.5, 4.5, 5.5]);
+        let embedded = one_hot.multiply_dense(&embedding).unwrap();
+        assert_eq!(embedded.data, vec![4.5, 5.5, 0.5, 1.5, 2.5, 3.5, 4.5, 5.5]);
+    }
+}
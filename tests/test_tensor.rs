@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod tests {
+    use rustic_ml::data_utils::matrix::{Matrix, MatrixError};
+    use rustic_ml::data_utils::tensor::Tensor;
+
+    /// Tensor of shape (2, 3, 4) with the values 0 to 23
+    fn range_tensor() -> Tensor {
+        Tensor::from_vec(&[2, 3, 4], (0..24).map(|x| x as f32).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_tensor_constructors() {
+        let zeros: Tensor = Tensor::new(&[2, 3, 4, 5]);
+        assert_eq!(zeros.shape(), &[2, 3, 4, 5]);
+        assert_eq!(zeros.strides(), &[60, 20, 5, 1]);
+        assert_eq!(zeros.ndim(), 4);
+        assert_eq!(zeros.len(), 120);
+        assert!(zeros.iter().all(|x| *x == 0.0));
+
+        let full = Tensor::full(&[3], 2.5_f64);
+        assert_eq!(full.to_vec(), vec![2.5, 2.5, 2.5]);
+
+        let empty: Tensor = Tensor::new(&[2, 0, 3]);
+        assert!(empty.is_empty());
+
+        let wrong_size: Result<Tensor, MatrixError> = Tensor::from_vec(&[2, 3], vec![1.0; 5]);
+        assert_eq!(
+            wrong_size,
+            Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: "2x3".to_string(),
+                second_matrix_shape: "5".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_tensor_indexing() {
+        let mut tensor = range_tensor();
+
+        assert_eq!(tensor.get(&[1, 2, 3]), Some(&23.0));
+        assert_eq!(tensor.get(&[0, 1, 2]), Some(&6.0));
+        assert_eq!(tensor[&[1, 0, 0][..]], 12.0);
+        assert_eq!(tensor.get(&[2, 0, 0]), None);
+        assert_eq!(tensor.get(&[0, 0]), None);
+
+        *tensor.get_mut(&[0, 0, 1]).unwrap() = 100.0;
+        tensor[&[0, 0, 2][..]] = 200.0;
+        assert_eq!(&tensor.to_vec()[..4], &[0.0, 100.0, 200.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_tensor_index_out_of_bounds() {
+        let tensor = range_tensor();
+        let _ = tensor[&[0, 3, 0][..]];
+    }
+
+    #[test]
+    fn test_tensor_reshape() {
+        let tensor = range_tensor();
+
+        let reshaped = tensor.reshape(&[4, 6]).unwrap();
+        assert_eq!(reshaped.shape(), &[4, 6]);
+        assert_eq!(reshaped.to_vec(), tensor.to_vec());
+        assert_eq!(reshaped.get(&[1, 0]), Some(&6.0));
+
+        assert!(matches!(
+            tensor.reshape(&[5, 5]),
+            Err(MatrixError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tensor_permute() {
+        let tensor = range_tensor();
+
+        // (2, 3, 4) to (4, 2, 3)
+        let permuted = tensor.permute(&[2, 0, 1]).unwrap();
+        assert_eq!(permuted.shape(), &[4, 2, 3]);
+        assert!(!permuted.is_contiguous());
+        for a in 0..2 {
+            for b in 0..3 {
+                for c in 0..4 {
+                    assert_eq!(permuted.get(&[c, a, b]), tensor.get(&[a, b, c]));
+                }
+            }
+        }
+
+        // Reshaping reads the values in the permuted order
+        let contiguous = permuted.to_contiguous();
+        assert!(contiguous.is_contiguous());
+        assert_eq!(contiguous, permuted);
+        assert_eq!(
+            &permuted.reshape(&[24]).unwrap().to_vec()[..4],
+            &[0.0, 4.0, 8.0, 12.0]
+        );
+
+        // Permuting back gives the original tensor
+        assert_eq!(permuted.permute(&[1, 2, 0]).unwrap(), tensor);
+
+        assert!(matches!(
+            tensor.permute(&[0, 0, 1]),
+            Err(MatrixError::IllegalRange(_))
+        ));
+        assert!(matches!(
+            tensor.permute(&[1, 0]),
+            Err(MatrixError::IllegalRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_tensor_slice_and_select() {
+        let tensor = range_tensor();
+
+        let sliced = tensor.slice(&[1..2, 0..3, 1..3]).unwrap();
+        assert_eq!(sliced.shape(), &[1, 3, 2]);
+        assert_eq!(sliced.to_vec(), vec![13.0, 14.0, 17.0, 18.0, 21.0, 22.0]);
+
+        // Axes without a range are kept in full
+        let first_rows = tensor.slice(&[0..2, 0..1]).unwrap();
+        assert_eq!(first_rows.shape(), &[2, 1, 4]);
+        assert_eq!(
+            first_rows.to_vec(),
+            vec![0.0, 1.0, 2.0, 3.0, 12.0, 13.0, 14.0, 15.0]
+        );
+
+        // Slicing a permuted tensor
+        let transposed = tensor.permute(&[0, 2, 1]).unwrap();
+        let column = transposed.slice(&[0..1, 1..2]).unwrap();
+        assert_eq!(column.to_vec(), vec![1.0, 5.0, 9.0]);
+
+        let second = tensor.select(0, 1).unwrap();
+        assert_eq!(second.shape(), &[3, 4]);
+        assert_eq!(
+            second.to_vec(),
+            (12..24).map(|x| x as f32).collect::<Vec<f32>>()
+        );
+
+        let last_col = tensor.select(2, 3).unwrap();
+        assert_eq!(last_col.shape(), &[2, 3]);
+        assert_eq!(last_col.to_vec(), vec![3.0, 7.0, 11.0, 15.0, 19.0, 23.0]);
+
+        assert!(matches!(
+            tensor.slice(&[0..3, 0..1]),
+            Err(MatrixError::IllegalRange(_))
+        ));
+        assert!(matches!(
+            tensor.slice(&[0..1, 0..1, 0..1, 0..1]),
+            Err(MatrixError::IllegalRange(_))
+        ));
+        assert!(matches!(
+            tensor.select(3, 0),
+            Err(MatrixError::IllegalRange(_))
+        ));
+        assert!(matches!(
+            tensor.select(1, 3),
+            Err(MatrixError::IllegalRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_tensor_broadcasting() {
+        let tensor = range_tensor();
+
+        // Adding a bias to the last axis
+        let bias: Tensor = Tensor::from_vec(&[4], vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let added = tensor.broadcast_add(&bias).unwrap();
+        assert_eq!(added.shape(), &[2, 3, 4]);
+        assert_eq!(
+            &added.to_vec()[..8],
+            &[1.0, 3.0, 5.0, 7.0, 5.0, 7.0, 9.0, 11.0]
+        );
+
+        // Scaling each batch item
+        let scale: Tensor = Tensor::from_vec(&[2, 1, 1], vec![1.0, -1.0]).unwrap();
+        let scaled = tensor.broadcast_mul(&scale).unwrap();
+        assert_eq!(scaled.get(&[0, 2, 3]), Some(&11.0));
+        assert_eq!(scaled.get(&[1, 2, 3]), Some(&-23.0));
+
+        // Both tensors are broadcast
+        let col: Tensor = Tensor::from_vec(&[3, 1], vec![1.0, 2.0, 3.0]).unwrap();
+        let row: Tensor = Tensor::from_vec(&[1, 2], vec![10.0, 20.0]).unwrap();
+        assert_eq!(col.broadcast_shape(&row).unwrap(), vec![3, 2]);
+        assert_eq!(
+            row.broadcast_sub(&col).unwrap().to_vec(),
+            vec![9.0, 19.0, 8.0, 18.0, 7.0, 17.0]
+        );
+        assert_eq!(
+            row.broadcast_div(&col).unwrap().to_vec(),
+            vec![10.0, 20.0, 5.0, 10.0, 10.0 / 3.0, 20.0 / 3.0]
+        );
+
+        let wrong: Tensor = Tensor::new(&[3]);
+        assert_eq!(
+            tensor.broadcast_add(&wrong),
+            Err(MatrixError::ShapeMismatch {
+                first_matrix_shape: "2x3x4".to_string(),
+                second_matrix_shape: "3".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_tensor_map_and_sum() {
+        let mut tensor = range_tensor();
+        assert_eq!(tensor.sum(), 276.0);
+
+        let relu = tensor.map(|x| (x - 12.0).max(0.0));
+        assert_eq!(relu.sum(), 66.0);
+
+        let double: Tensor<f64> = tensor.map(|x| x as f64);
+        assert_eq!(double.shape(), tensor.shape());
+
+        tensor.scale_f(2.0);
+        assert_eq!(tensor.sum(), 552.0);
+    }
+
+    #[test]
+    fn test_tensor_matmul() {
+        let tensor = range_tensor();
+        let rhs: Tensor = Tensor::from_vec(&[4, 2], (0..8).map(|x| x as f32).collect()).unwrap();
+
+        // A batch of (3x4) matrices times a single (4x2) matrix
+        let product = tensor.matmul(&rhs).unwrap();
+        assert_eq!(product.shape(), &[2, 3, 2]);
+        let rhs_matrix = rhs.to_matrix().unwrap();
+        for batch in 0..2 {
+            let lhs = tensor.select(0, batch).unwrap().to_matrix().unwrap();
+            let expected = lhs.multiply(&rhs_matrix).unwrap();
+            assert_eq!(
+                product.select(0, batch).unwrap().to_matrix().unwrap(),
+                expected
+            );
+        }
+
+        // Batch axes are broadcast, and permuted tensors are read by their strides
+        let transposed = tensor.permute(&[0, 2, 1]).unwrap();
+        let gram = tensor.matmul(&transposed).unwrap();
+        assert_eq!(gram.shape(), &[2, 3, 3]);
+        let first = tensor.select(0, 0).unwrap().to_matrix().unwrap();
+        assert_eq!(
+            gram.select(0, 0).unwrap().to_matrix().unwrap(),
+            first.multiply(&first.get_transposed()).unwrap()
+        );
+
+        let batched_rhs: Tensor = Tensor::new(&[5, 1, 4, 2]);
+        assert_eq!(tensor.matmul(&batched_rhs).unwrap().shape(), &[5, 2, 3, 2]);
+
+        assert_eq!(tensor.matmul(&tensor), Err(MatrixError::MatrixMultiply));
+        assert_eq!(
+            tensor.matmul(&Tensor::new(&[4])),
+            Err(MatrixError::DimensionMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(matches!(
+            tensor.matmul(&Tensor::new(&[3, 4, 2])),
+            Err(MatrixError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tensor_matrix_conversion() {
+        let matrix: Matrix = Matrix::from_vec(3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let tensor = Tensor::from(&matrix);
+        assert_eq!(tensor.shape(), &[2, 3]);
+        assert_eq!(tensor.get(&[1, 0]), Some(&4.0));
+        assert_eq!(tensor.to_matrix().unwrap(), matrix);
+
+        // A permuted tensor gives the transposed matrix
+        let transposed = Tensor::from(matrix.clone()).permute(&[1, 0]).unwrap();
+        assert_eq!(transposed.to_matrix().unwrap(), matrix.get_transposed());
+
+        assert_eq!(
+            range_tensor().to_matrix(),
+            Err(MatrixError::DimensionMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+}